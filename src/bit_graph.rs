@@ -0,0 +1,454 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// Returns the number of `u64` words needed to store a row
+/// of a [BitGraph] with `size` vertices.
+/// ```
+/// use const_graphs::bit_graph_words;
+///
+/// assert_eq!(bit_graph_words(1), 1);
+/// assert_eq!(bit_graph_words(64), 1);
+/// assert_eq!(bit_graph_words(65), 2);
+/// ```
+pub const fn bit_graph_words(size: usize) -> usize {
+  size.div_ceil(64)
+}
+
+/// Compile time graphs that store each edge in a single
+/// bit, using 8 times less memory than a [Graph].
+///
+/// Because the number of words per row can't be computed
+/// from `SIZE` in a type, it has to be passed as `WORDS`,
+/// and must be equal to [bit_graph_words]`(SIZE)`.
+/// ```
+/// use const_graphs::{bit_graph_words, BitGraph};
+///
+/// const SIZE: usize = 1_000;
+/// const WORDS: usize = bit_graph_words(SIZE);
+/// // You can use const.
+/// const graph1: BitGraph<SIZE, WORDS> = BitGraph::new();
+///
+/// // And, static.
+/// static mut graph2: BitGraph<SIZE, WORDS> =
+///   BitGraph::new();
+/// unsafe {
+///   graph2.add_edge(0, 1);
+///   assert!(graph2.has_edge(0, 1));
+/// }
+///
+/// // And, of course, let too:
+/// let graph3 = BitGraph::<SIZE, WORDS>::new();
+/// ```
+///
+/// [Graph]: crate::Graph
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BitGraph<const SIZE: usize, const WORDS: usize> {
+  data: [[u64; WORDS]; SIZE],
+}
+
+impl<const SIZE: usize, const WORDS: usize>
+  BitGraph<SIZE, WORDS>
+{
+  const VALID_WORDS: () = assert!(
+    WORDS == bit_graph_words(SIZE),
+    "WORDS must be equal to bit_graph_words(SIZE)"
+  );
+
+  /// Returns the word index and the mask of the bit that
+  /// stores the edge to `j`.
+  const fn bit(j: usize) -> (usize, u64) {
+    assert!(j < SIZE, "vertex out of bounds");
+
+    (j / 64, 1 << (j % 64))
+  }
+
+  /// Add an edge to the graph between `i` and `j`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<10, 1>::new();
+  /// graph.add_edge(0, 1);
+  /// assert!(graph.has_edge(0, 1));
+  /// ```
+  /// See also [BitGraph::add_edge_undirected].
+  pub const fn add_edge(&mut self, i: usize, j: usize) {
+    let (word, mask) = Self::bit(j);
+    self.data[i][word] |= mask;
+  }
+
+  /// Add an undirected edge to the graph between `i` and
+  /// `j`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<10, 1>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// assert!(graph.has_edge(0, 1));
+  /// assert!(graph.has_edge(1, 0));
+  /// ```
+  /// See also [BitGraph::add_edge].
+  pub const fn add_edge_undirected(
+    &mut self,
+    i: usize,
+    j: usize,
+  ) {
+    self.add_edge(i, j);
+    self.add_edge(j, i);
+  }
+
+  /// Remove an edge from the graph between `i` and `j`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<10, 1>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.remove_edge(0, 1);
+  /// assert!(!graph.has_edge(0, 1));
+  /// ```
+  /// See also [BitGraph::remove_edge_undirected].
+  pub const fn remove_edge(&mut self, i: usize, j: usize) {
+    let (word, mask) = Self::bit(j);
+    self.data[i][word] &= !mask;
+  }
+
+  /// Remove an undirected edge from the graph between `i`
+  /// and `j`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<10, 1>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.remove_edge_undirected(0, 1);
+  /// assert!(!graph.has_edge(0, 1));
+  /// assert!(!graph.has_edge(1, 0));
+  /// ```
+  /// See also [BitGraph::remove_edge].
+  pub const fn remove_edge_undirected(
+    &mut self,
+    i: usize,
+    j: usize,
+  ) {
+    self.remove_edge(i, j);
+    self.remove_edge(j, i);
+  }
+
+  /// Checks whether there is an edge between `i` and `j`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let graph = BitGraph::<10, 1>::new();
+  /// // The graph is initialized empty.
+  /// assert!(!graph.has_edge(0, 1));
+  /// ```
+  pub const fn has_edge(&self, i: usize, j: usize) -> bool {
+    let (word, mask) = Self::bit(j);
+    self.data[i][word] & mask != 0
+  }
+
+  /// Returns a [BitRow] where the ith element is a boolean
+  /// representing whether there is an edge between `vertex`
+  /// and `i`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge(0, 2);
+  /// assert!(graph.get_edges(0).get(2));
+  /// assert!(graph
+  ///   .get_edges(0)
+  ///   .iter()
+  ///   .eq([false, false, true]));
+  /// ```
+  /// See also [BitGraph::get_inverse_edges].
+  pub const fn get_edges(
+    &self,
+    vertex: usize,
+  ) -> BitRow<'_, SIZE, WORDS> {
+    BitRow {
+      data: &self.data[vertex],
+    }
+  }
+
+  /// Returns an array where the ith element is a boolean
+  /// representing whether there is an edge between `i` and
+  /// `vertex`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge(0, 2);
+  /// assert_eq!(
+  ///   graph.get_inverse_edges(2),
+  ///   [true, false, false]
+  /// );
+  /// ```
+  /// See also [BitGraph::get_edges].
+  pub const fn get_inverse_edges(
+    &self,
+    vertex: usize,
+  ) -> [bool; SIZE] {
+    let mut edges = [false; SIZE];
+
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      edges[neighbor] = self.has_edge(neighbor, vertex);
+
+      neighbor += 1;
+    }
+
+    edges
+  }
+
+  /// Returns the maximum number of edges of a graph.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let graph = BitGraph::<3, 1>::new();
+  /// assert_eq!(graph.max_number_of_edges(), 6);
+  /// ```
+  pub const fn max_number_of_edges(&self) -> usize {
+    SIZE * (SIZE - 1)
+  }
+
+  /// Returns the number of directed edges of a graph.
+  /// Just like in [BitGraph::max_number_of_edges],
+  /// self-loops are not counted.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 2);
+  /// assert_eq!(graph.count_edges(), 3);
+  /// ```
+  pub const fn count_edges(&self) -> usize {
+    let mut edges = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut word = 0;
+      while word < WORDS {
+        edges += self.data[i][word].count_ones() as usize;
+
+        word += 1;
+      }
+
+      if self.has_edge(i, i) {
+        edges -= 1;
+      }
+      i += 1;
+    }
+
+    edges
+  }
+
+  /// Returns the density of a graph, that is, the ratio
+  /// between the number of edges and the maximum number of
+  /// possible edges.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(0, 2);
+  /// graph.add_edge_undirected(1, 2);
+  /// assert_eq!(graph.density(), 1.0);
+  /// ```
+  pub const fn density(&self) -> f32 {
+    self.count_edges() as f32
+      / self.max_number_of_edges() as f32
+  }
+
+  /// Remove all edges from the graph.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(0, 2);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.clear();
+  ///
+  /// assert_eq!(graph.density(), 0.0);
+  /// ```
+  pub const fn clear(&mut self) {
+    let mut i = 0;
+    while i < SIZE {
+      let mut word = 0;
+      while word < WORDS {
+        self.data[i][word] = 0;
+
+        word += 1;
+      }
+      i += 1;
+    }
+  }
+
+  /// Creates a new bit graph.
+  ///
+  /// Fails to compile if `WORDS` isn't equal to
+  /// [bit_graph_words]`(SIZE)`.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let graph = BitGraph::<100, 2>::new();
+  /// ```
+  /// ```compile_fail
+  /// use const_graphs::BitGraph;
+  ///
+  /// let graph = BitGraph::<100, 1>::new();
+  /// ```
+  pub const fn new() -> BitGraph<SIZE, WORDS> {
+    #[allow(clippy::let_unit_value)]
+    let () = Self::VALID_WORDS;
+
+    BitGraph {
+      data: [[0; WORDS]; SIZE],
+    }
+  }
+
+  /// Creates a new bit graph on the heap, without creating
+  /// it on the stack first, so it works even for graphs too
+  /// large for the stack.
+  /// Requires the `alloc` feature.
+  ///
+  /// Just like [BitGraph::new], fails to compile if `WORDS`
+  /// isn't equal to [bit_graph_words]`(SIZE)`.
+  /// ```
+  /// use const_graphs::{bit_graph_words, BitGraph};
+  ///
+  /// const SIZE: usize = 10_000;
+  /// const WORDS: usize = bit_graph_words(SIZE);
+  /// let mut graph = BitGraph::<SIZE, WORDS>::new_boxed();
+  /// graph.add_edge(0, 9_999);
+  /// assert!(graph.has_edge(0, 9_999));
+  /// ```
+  /// See also [Graph::new_boxed](crate::Graph::new_boxed).
+  #[cfg(feature = "alloc")]
+  pub fn new_boxed() -> Box<BitGraph<SIZE, WORDS>> {
+    #[allow(clippy::let_unit_value)]
+    let () = Self::VALID_WORDS;
+
+    // SAFETY: a bit graph is only words, so all zeros is a
+    // valid graph, and it is the one without edges.
+    unsafe { Box::new_zeroed().assume_init() }
+  }
+}
+
+impl<const SIZE: usize, const WORDS: usize> Default
+  for BitGraph<SIZE, WORDS>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A borrowed row of a [BitGraph], returned by
+/// [BitGraph::get_edges].
+#[derive(Clone, Copy)]
+pub struct BitRow<'a, const SIZE: usize, const WORDS: usize>
+{
+  data: &'a [u64; WORDS],
+}
+
+impl<'a, const SIZE: usize, const WORDS: usize>
+  BitRow<'a, SIZE, WORDS>
+{
+  /// Checks whether the ith bit of the row is set.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge(0, 1);
+  /// assert!(graph.get_edges(0).get(1));
+  /// assert!(!graph.get_edges(0).get(2));
+  /// ```
+  pub const fn get(&self, i: usize) -> bool {
+    assert!(i < SIZE, "vertex out of bounds");
+
+    self.data[i / 64] & (1 << (i % 64)) != 0
+  }
+
+  /// Returns an iterator over the bits of the row, yielding
+  /// `SIZE` booleans.
+  /// ```
+  /// use const_graphs::BitGraph;
+  ///
+  /// let mut graph = BitGraph::<3, 1>::new();
+  /// graph.add_edge(0, 1);
+  /// for (neighbor, has_edge) in
+  ///   graph.get_edges(0).iter().enumerate()
+  /// {
+  ///   assert_eq!(has_edge, neighbor == 1);
+  /// }
+  /// ```
+  pub const fn iter(&self) -> BitRowIter<'a, SIZE, WORDS> {
+    BitRowIter {
+      row: *self,
+      start: 0,
+      end: SIZE,
+    }
+  }
+}
+
+impl<'a, const SIZE: usize, const WORDS: usize> IntoIterator
+  for BitRow<'a, SIZE, WORDS>
+{
+  type Item = bool;
+  type IntoIter = BitRowIter<'a, SIZE, WORDS>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the bits of a [BitRow].
+#[derive(Clone)]
+pub struct BitRowIter<
+  'a,
+  const SIZE: usize,
+  const WORDS: usize,
+> {
+  row: BitRow<'a, SIZE, WORDS>,
+  start: usize,
+  end: usize,
+}
+
+impl<const SIZE: usize, const WORDS: usize> Iterator
+  for BitRowIter<'_, SIZE, WORDS>
+{
+  type Item = bool;
+
+  fn next(&mut self) -> Option<bool> {
+    if self.start == self.end {
+      return None;
+    }
+
+    self.start += 1;
+    Some(self.row.get(self.start - 1))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.end - self.start;
+    (len, Some(len))
+  }
+}
+
+impl<const SIZE: usize, const WORDS: usize>
+  DoubleEndedIterator for BitRowIter<'_, SIZE, WORDS>
+{
+  fn next_back(&mut self) -> Option<bool> {
+    if self.start == self.end {
+      return None;
+    }
+
+    self.end -= 1;
+    Some(self.row.get(self.end))
+  }
+}
+
+impl<const SIZE: usize, const WORDS: usize>
+  ExactSizeIterator for BitRowIter<'_, SIZE, WORDS>
+{
+}
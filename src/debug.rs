@@ -0,0 +1,92 @@
+use core::fmt;
+
+/// The maximum number of vertices shown by the [Debug]
+/// implementations of the graphs, the remaining ones are
+/// summarized.
+pub(crate) const MAX_VERTICES: usize = 32;
+
+/// A writer that only counts how many characters were
+/// written to it.
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.0 += s.chars().count();
+    Ok(())
+  }
+}
+
+/// Returns the number of characters of the [Debug]
+/// representation of `value`.
+pub(crate) fn width(value: &dyn fmt::Debug) -> usize {
+  let mut counter = Counter(0);
+  // Writing to a counter never fails.
+  let _ =
+    fmt::write(&mut counter, format_args!("{value:?}"));
+  counter.0
+}
+
+/// Writes `value` right-aligned in a column of `width`
+/// characters.
+pub(crate) fn write_padded(
+  f: &mut fmt::Formatter<'_>,
+  width: usize,
+  value: &dyn fmt::Debug,
+) -> fmt::Result {
+  let padding = width.saturating_sub(self::width(value));
+  write!(f, "{:padding$}{value:?}", "")
+}
+
+/// Writes a header with the index of the first `shown`
+/// vertices in columns of `width` characters.
+pub(crate) fn write_header(
+  f: &mut fmt::Formatter<'_>,
+  shown: usize,
+  total: usize,
+  width: usize,
+) -> fmt::Result {
+  let label_width = self::width(&shown.saturating_sub(1));
+  write!(f, "{:label_width$}", "")?;
+
+  for j in 0..shown {
+    write!(f, " ")?;
+    write_padded(f, width, &j)?;
+  }
+
+  if shown < total {
+    write!(f, " ...")?;
+  }
+
+  Ok(())
+}
+
+/// Writes the label of the row of `vertex`, in a column as
+/// wide as the largest index of the first `shown`
+/// vertices.
+pub(crate) fn write_label(
+  f: &mut fmt::Formatter<'_>,
+  vertex: usize,
+  shown: usize,
+) -> fmt::Result {
+  writeln!(f)?;
+  write_padded(f, width(&shown.saturating_sub(1)), &vertex)
+}
+
+/// Writes the end of a row and, after the last row, the
+/// number of vertices that were not shown.
+pub(crate) fn write_row_end(
+  f: &mut fmt::Formatter<'_>,
+  vertex: usize,
+  shown: usize,
+  total: usize,
+) -> fmt::Result {
+  if shown < total {
+    write!(f, " ...")?;
+
+    if vertex + 1 == shown {
+      write!(f, "\n... {} more vertices", total - shown)?;
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,152 @@
+use core::fmt;
+
+/// The errors of the fallible graph methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphError {
+  /// A vertex isn't smaller than the number of vertices of
+  /// the graph.
+  VertexOutOfBounds {
+    /// The vertex that was given.
+    vertex: usize,
+    /// The number of vertices of the graph.
+    size: usize,
+  },
+  /// A vertex appears more than once where each vertex
+  /// should appear exactly once, like in a permutation.
+  RepeatedVertex {
+    /// The vertex that was repeated.
+    vertex: usize,
+  },
+}
+
+impl GraphError {
+  /// Returns an error if `vertex` is out of bounds for a
+  /// graph with `size` vertices.
+  pub(crate) const fn check_vertex(
+    vertex: usize,
+    size: usize,
+  ) -> Result<(), GraphError> {
+    if vertex < size {
+      Ok(())
+    } else {
+      Err(GraphError::VertexOutOfBounds { vertex, size })
+    }
+  }
+
+  /// Returns an error if `permutation` isn't a permutation
+  /// of the vertices of a graph with `SIZE` vertices.
+  pub(crate) const fn check_permutation<
+    const SIZE: usize,
+  >(
+    permutation: &[usize; SIZE],
+  ) -> Result<(), GraphError> {
+    let mut seen = [false; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let vertex = permutation[i];
+      if let Err(error) =
+        GraphError::check_vertex(vertex, SIZE)
+      {
+        return Err(error);
+      }
+      if seen[vertex] {
+        return Err(GraphError::RepeatedVertex { vertex });
+      }
+      seen[vertex] = true;
+
+      i += 1;
+    }
+
+    Ok(())
+  }
+}
+
+impl fmt::Display for GraphError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      GraphError::VertexOutOfBounds { vertex, size } => {
+        write!(
+        f,
+        "the vertex {vertex} is out of bounds for a graph \
+         with {size} vertices"
+      )
+      }
+      GraphError::RepeatedVertex { vertex } => {
+        write!(f, "the vertex {vertex} is repeated")
+      }
+    }
+  }
+}
+
+impl core::error::Error for GraphError {}
+
+/// The errors of parsing a graph, like in
+/// [Graph::from_dot](crate::Graph::from_dot).
+/// Requires the `interop` feature.
+#[cfg(feature = "interop")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+  /// The input doesn't start with `digraph {` or
+  /// `graph {`, optionally with a name before the `{`.
+  InvalidHeader,
+  /// The input doesn't end with a `}`.
+  MissingClosingBrace,
+  /// A statement is neither a vertex nor an edge, like an
+  /// edge with `--` in a `digraph`, with `->` in a `graph`
+  /// or with unclosed attributes or quotes.
+  InvalidStatement {
+    /// The line of the statement, starting at `1`.
+    line: usize,
+  },
+  /// A vertex isn't a non-negative integer.
+  InvalidVertex {
+    /// The line of the vertex, starting at `1`.
+    line: usize,
+  },
+  /// A vertex isn't smaller than the number of vertices of
+  /// the graph.
+  VertexOutOfBounds {
+    /// The line of the vertex, starting at `1`.
+    line: usize,
+    /// The vertex that was given.
+    vertex: usize,
+    /// The number of vertices of the graph.
+    size: usize,
+  },
+}
+
+#[cfg(feature = "interop")]
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseError::InvalidHeader => write!(
+        f,
+        "expected `digraph {{` or `graph {{` at the start"
+      ),
+      ParseError::MissingClosingBrace => {
+        write!(f, "expected `}}` at the end")
+      }
+      ParseError::InvalidStatement { line } => {
+        write!(f, "invalid statement at line {line}")
+      }
+      ParseError::InvalidVertex { line } => {
+        write!(f, "invalid vertex at line {line}")
+      }
+      ParseError::VertexOutOfBounds {
+        line,
+        vertex,
+        size,
+      } => {
+        write!(
+          f,
+          "the vertex {vertex} at line {line} is out of \
+           bounds for a graph with {size} vertices"
+        )
+      }
+    }
+  }
+}
+
+#[cfg(feature = "interop")]
+impl core::error::Error for ParseError {}
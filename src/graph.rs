@@ -1,3 +1,19 @@
+use crate::debug;
+use crate::random::XorShift64;
+#[cfg(feature = "interop")]
+use crate::ParseError;
+use crate::{GraphError, WeightedGraph};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+use core::ops::{Index, IndexMut};
+
 /// Compile time graphs.
 /// ```
 /// use const_graphs::Graph;
@@ -15,12 +31,133 @@
 ///
 /// // And, of course, let too:
 /// let graph3 = Graph::<SIZE>::new();
+///
+/// // Graphs with the same edges are equal.
+/// let mut graph4 = Graph::<SIZE>::new();
+/// graph4.add_edge(0, 1);
+/// assert!(graph3 != graph4);
+/// assert!(graph4.transpose().transpose() == graph4);
+///
+/// // And can be copied before being mutated.
+/// let mut graph5 = graph4;
+/// graph5.remove_edge(0, 1);
+/// assert!(graph4.has_edge(0, 1));
+/// assert!(!graph5.has_edge(0, 1));
 /// ```
+#[derive(Clone, Copy, Eq)]
 pub struct Graph<const SIZE: usize> {
   data: [[bool; SIZE]; SIZE],
+  /// The number of edges between different vertices, kept
+  /// up to date so it doesn't need to be counted.
+  edge_count: usize,
+  /// Whether the matrix may have been changed through a
+  /// mutable reference since `edge_count` was last right,
+  /// in which case the edges are counted again by the next
+  /// change through a method.
+  edge_count_stale: bool,
 }
 
 impl<const SIZE: usize> Graph<SIZE> {
+  /// Checks whether there is an edge between `i` and `j`
+  /// in any direction, used by the algorithms that treat
+  /// the graph as undirected.
+  const fn has_edge_undirected(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> bool {
+    self.data[i][j] || self.data[j][i]
+  }
+
+  /// Returns the number of vertices other than `vertex`
+  /// with an edge between them and `vertex` in any
+  /// direction, used by the algorithms that treat the graph
+  /// as undirected.
+  const fn undirected_degree(
+    &self,
+    vertex: usize,
+  ) -> usize {
+    let mut degree = 0;
+
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      if neighbor != vertex
+        && self.has_edge_undirected(vertex, neighbor)
+      {
+        degree += 1;
+      }
+
+      neighbor += 1;
+    }
+
+    degree
+  }
+
+  /// Returns the number of neighbors of each vertex when
+  /// the graph is treated as undirected.
+  const fn undirected_degrees(&self) -> [usize; SIZE] {
+    let mut degrees = [0; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      degrees[vertex] = self.undirected_degree(vertex);
+
+      vertex += 1;
+    }
+
+    degrees
+  }
+
+  /// Sets whether there is an edge between `i` and `j`,
+  /// updating the number of edges if it changes, after
+  /// counting them again if they were changed through a
+  /// mutable reference.
+  /// Every change to the matrix, except for the ones
+  /// through mutable references, goes through it.
+  const fn set_edge_state(
+    &mut self,
+    i: usize,
+    j: usize,
+    edge: bool,
+  ) {
+    if self.edge_count_stale {
+      self.recount_edges();
+    }
+
+    if i != j && self.data[i][j] != edge {
+      if edge {
+        self.edge_count += 1;
+      } else {
+        self.edge_count -= 1;
+      }
+    }
+
+    self.data[i][j] = edge;
+  }
+
+  /// Returns the number of edges between different
+  /// vertices in `data`.
+  const fn count_matrix_edges(
+    data: &[[bool; SIZE]; SIZE],
+  ) -> usize {
+    let mut edges = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if i != j && data[i][j] {
+          edges += 1;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    edges
+  }
+
   /// Add an edge to the graph between `i` and `j`.
   /// ```
   /// use const_graphs::Graph;
@@ -31,7 +168,7 @@ impl<const SIZE: usize> Graph<SIZE> {
   /// ```
   /// See also [Graph::add_edge_undirected].
   pub const fn add_edge(&mut self, i: usize, j: usize) {
-    self.data[i][j] = true;
+    self.set_edge_state(i, j, true);
   }
 
   /// Add an undirected edge to the graph between `i` and
@@ -50,8 +187,8 @@ impl<const SIZE: usize> Graph<SIZE> {
     i: usize,
     j: usize,
   ) {
-    self.data[i][j] = true;
-    self.data[j][i] = true;
+    self.set_edge_state(i, j, true);
+    self.set_edge_state(j, i, true);
   }
 
   /// Remove an edge from the graph between `i` and `j`.
@@ -65,7 +202,7 @@ impl<const SIZE: usize> Graph<SIZE> {
   /// ```
   /// See also [Graph::remove_edge_undirected].
   pub const fn remove_edge(&mut self, i: usize, j: usize) {
-    self.data[i][j] = false;
+    self.set_edge_state(i, j, false);
   }
 
   /// Remove an undirected edge from the graph between `i`
@@ -85,8 +222,273 @@ impl<const SIZE: usize> Graph<SIZE> {
     i: usize,
     j: usize,
   ) {
-    self.data[i][j] = false;
-    self.data[j][i] = false;
+    self.set_edge_state(i, j, false);
+    self.set_edge_state(j, i, false);
+  }
+
+  /// Add an edge to the graph between `i` and `j` if
+  /// `present`, or remove it otherwise.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// for i in 0..3 {
+  ///   graph.set_edge(i, 2, i % 2 == 0);
+  /// }
+  /// assert!(graph.has_edge(0, 2));
+  /// assert!(!graph.has_edge(1, 2));
+  ///
+  /// graph.set_edge(0, 2, false);
+  /// assert!(!graph.has_edge(0, 2));
+  /// ```
+  /// See also [Graph::toggle_edge].
+  pub const fn set_edge(
+    &mut self,
+    i: usize,
+    j: usize,
+    present: bool,
+  ) {
+    self.set_edge_state(i, j, present);
+  }
+
+  /// Add an edge to the graph between `i` and `j` if there
+  /// is none, or remove it otherwise.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.toggle_edge(0, 1);
+  /// assert!(graph.has_edge(0, 1));
+  ///
+  /// graph.toggle_edge(0, 1);
+  /// assert!(!graph.has_edge(0, 1));
+  /// ```
+  /// See also [Graph::set_edge].
+  pub const fn toggle_edge(&mut self, i: usize, j: usize) {
+    self.set_edge_state(i, j, !self.data[i][j]);
+  }
+
+  /// Add an edge to the graph between `i` and `j` for each
+  /// `(i, j)` in `edges`.
+  ///
+  /// Panics if any vertex is out of bounds, before adding
+  /// any edge after it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edges(&[(0, 1), (1, 2)]);
+  /// assert!(graph.has_edge(0, 1));
+  /// assert!(graph.has_edge(1, 2));
+  /// assert!(!graph.has_edge(1, 0));
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edges(&[(0, 3)]);
+  /// ```
+  /// See also [Graph::add_edges_undirected].
+  pub fn add_edges(&mut self, edges: &[(usize, usize)]) {
+    for &(i, j) in edges {
+      assert!(
+        i < SIZE && j < SIZE,
+        "the edge ({i}, {j}) is out of bounds for a graph \
+         with {SIZE} vertices"
+      );
+
+      self.add_edge(i, j);
+    }
+  }
+
+  /// Add an undirected edge to the graph between `i` and
+  /// `j` for each `(i, j)` in `edges`.
+  ///
+  /// Panics if any vertex is out of bounds, before adding
+  /// any edge after it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edges_undirected(&[(0, 1), (1, 2)]);
+  /// assert!(graph.has_edge(1, 0));
+  /// assert!(graph.has_edge(2, 1));
+  /// assert!(!graph.has_edge(0, 2));
+  /// ```
+  /// See also [Graph::add_edges].
+  pub fn add_edges_undirected(
+    &mut self,
+    edges: &[(usize, usize)],
+  ) {
+    for &(i, j) in edges {
+      assert!(
+        i < SIZE && j < SIZE,
+        "the edge ({i}, {j}) is out of bounds for a graph \
+         with {SIZE} vertices"
+      );
+
+      self.add_edge_undirected(i, j);
+    }
+  }
+
+  /// Add an edge to the graph between `i` and `j`, or
+  /// return an error if any of them is out of bounds.
+  /// ```
+  /// use const_graphs::{Graph, GraphError};
+  ///
+  /// let mut graph = Graph::<10>::new();
+  /// assert_eq!(graph.try_add_edge(0, 1), Ok(()));
+  /// assert!(graph.has_edge(0, 1));
+  /// assert_eq!(
+  ///   graph.try_add_edge(0, 10),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 10,
+  ///     size: 10,
+  ///   })
+  /// );
+  /// ```
+  /// See also [Graph::add_edge].
+  pub fn try_add_edge(
+    &mut self,
+    i: usize,
+    j: usize,
+  ) -> Result<(), GraphError> {
+    GraphError::check_vertex(i, SIZE)?;
+    GraphError::check_vertex(j, SIZE)?;
+
+    self.add_edge(i, j);
+    Ok(())
+  }
+
+  /// Remove an edge from the graph between `i` and `j`, or
+  /// return an error if any of them is out of bounds.
+  /// ```
+  /// use const_graphs::{Graph, GraphError};
+  ///
+  /// let mut graph = Graph::<10>::new();
+  /// graph.add_edge(0, 1);
+  /// assert_eq!(graph.try_remove_edge(0, 1), Ok(()));
+  /// assert!(!graph.has_edge(0, 1));
+  /// assert_eq!(
+  ///   graph.try_remove_edge(10, 0),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 10,
+  ///     size: 10,
+  ///   })
+  /// );
+  /// ```
+  /// See also [Graph::remove_edge].
+  pub fn try_remove_edge(
+    &mut self,
+    i: usize,
+    j: usize,
+  ) -> Result<(), GraphError> {
+    GraphError::check_vertex(i, SIZE)?;
+    GraphError::check_vertex(j, SIZE)?;
+
+    self.remove_edge(i, j);
+    Ok(())
+  }
+
+  /// Checks whether there is an edge between `i` and `j`,
+  /// or returns an error if any of them is out of bounds.
+  /// ```
+  /// use const_graphs::{Graph, GraphError};
+  ///
+  /// let mut graph = Graph::<10>::new();
+  /// graph.add_edge(0, 1);
+  /// assert_eq!(graph.try_has_edge(0, 1), Ok(true));
+  /// assert_eq!(
+  ///   graph.try_has_edge(0, 10),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 10,
+  ///     size: 10,
+  ///   })
+  /// );
+  /// ```
+  /// See also [Graph::has_edge].
+  pub fn try_has_edge(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> Result<bool, GraphError> {
+    GraphError::check_vertex(i, SIZE)?;
+    GraphError::check_vertex(j, SIZE)?;
+
+    Ok(self.has_edge(i, j))
+  }
+
+  /// Remove every edge from and to `vertex`.
+  /// The number of vertices of the graph is fixed, so the
+  /// vertex isn't removed, it just becomes isolated.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 0);
+  /// graph.remove_vertex(1);
+  /// assert!(!graph.has_edge(0, 1));
+  /// assert!(!graph.has_edge(1, 2));
+  /// assert!(graph.has_edge(2, 0));
+  /// ```
+  pub const fn remove_vertex(&mut self, vertex: usize) {
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      self.set_edge_state(vertex, neighbor, false);
+      self.set_edge_state(neighbor, vertex, false);
+
+      neighbor += 1;
+    }
+  }
+
+  /// Add a self-loop to the graph on `vertex`, that is, an
+  /// edge between `vertex` and itself.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<10>::new();
+  /// graph.add_self_loop(3);
+  /// assert!(graph.has_self_loop(3));
+  /// ```
+  /// See also [Graph::remove_self_loops].
+  pub const fn add_self_loop(&mut self, vertex: usize) {
+    self.data[vertex][vertex] = true;
+  }
+
+  /// Checks whether there is a self-loop on `vertex`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<10>::new();
+  /// graph.add_edge(3, 3);
+  /// assert!(graph.has_self_loop(3));
+  /// assert!(!graph.has_self_loop(4));
+  /// ```
+  pub const fn has_self_loop(&self, vertex: usize) -> bool {
+    self.data[vertex][vertex]
+  }
+
+  /// Remove every self-loop from the graph, leaving the
+  /// other edges untouched.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<10>::new();
+  /// graph.add_self_loop(3);
+  /// graph.add_edge(3, 4);
+  /// graph.remove_self_loops();
+  /// assert!(!graph.has_self_loop(3));
+  /// assert!(graph.has_edge(3, 4));
+  /// ```
+  pub const fn remove_self_loops(&mut self) {
+    let mut vertex = 0;
+    while vertex < SIZE {
+      self.data[vertex][vertex] = false;
+
+      vertex += 1;
+    }
   }
 
   /// Checks whether there is an edge between `i` and `j`.
@@ -101,6 +503,25 @@ impl<const SIZE: usize> Graph<SIZE> {
     self.data[i][j]
   }
 
+  /// Returns a reference to the adjacency matrix of the
+  /// graph, where the element `[i][j]` indicates whether
+  /// there is an edge between `i` and `j`, without copying
+  /// it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<2>::new();
+  /// graph.add_edge(0, 1);
+  /// assert_eq!(
+  ///   graph.as_matrix(),
+  ///   &[[false, true], [false, false]]
+  /// );
+  /// ```
+  /// See also [Graph::from_matrix].
+  pub const fn as_matrix(&self) -> &[[bool; SIZE]; SIZE] {
+    &self.data
+  }
+
   /// Returns an array where the ith element is a boolean
   /// representing whether there is an edge between `vertex`
   /// and `i`.
@@ -119,6 +540,77 @@ impl<const SIZE: usize> Graph<SIZE> {
     &self.data[vertex]
   }
 
+  /// Returns a mutable reference to the array where the ith
+  /// element is whether there is an edge between `vertex`
+  /// and `i`, so a whole row can be changed at once.
+  ///
+  /// The graph can't know what is done with the reference,
+  /// so [Graph::count_edges] and [Graph::density] take
+  /// `O(SIZE²)` time instead of constant time until the
+  /// next change through a method like [Graph::add_edge],
+  /// which counts the edges again once, or a call to
+  /// [Graph::recount_edges]; [Graph::set_edges] avoids
+  /// that.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(1, 0);
+  /// other.add_edge(1, 2);
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// *graph.get_edges_mut(0) = *other.get_edges(1);
+  /// assert_eq!(graph.get_edges(0), &[true, false, true]);
+  ///
+  /// graph.get_edges_mut(0)[1] = true;
+  /// assert!(graph.has_edge(0, 1));
+  /// assert_eq!(graph.count_edges(), 2);
+  /// ```
+  /// See also [Graph::get_edges].
+  pub const fn get_edges_mut(
+    &mut self,
+    vertex: usize,
+  ) -> &mut [bool; SIZE] {
+    self.edge_count_stale = true;
+    &mut self.data[vertex]
+  }
+
+  /// Replaces the edges from `vertex` with `edges`, where
+  /// the ith element is whether there is an edge between
+  /// `vertex` and `i`.
+  /// Unlike [Graph::get_edges_mut], it keeps the count of
+  /// the edges up to date.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(1, 0);
+  /// other.add_edge(1, 2);
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.set_edges(0, other.get_edges(1));
+  /// assert_eq!(graph.get_edges(0), &[true, false, true]);
+  /// assert_eq!(graph.count_edges(), 1);
+  /// ```
+  pub const fn set_edges(
+    &mut self,
+    vertex: usize,
+    edges: &[bool; SIZE],
+  ) {
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      self.set_edge_state(
+        vertex,
+        neighbor,
+        edges[neighbor],
+      );
+
+      neighbor += 1;
+    }
+  }
+
   /// Returns an array where the ith element is a boolean
   /// representing whether there is an edge between `i` and
   /// `vertex`.
@@ -152,45 +644,4407 @@ impl<const SIZE: usize> Graph<SIZE> {
     edges
   }
 
-  /// Returns the maximum number of edges of a graph.
+  /// Checks whether there is an edge between `i` and `j`,
+  /// or returns `None` if any of them is out of bounds.
   /// ```
   /// use const_graphs::Graph;
   ///
-  /// let graph = Graph::<3>::new();
-  /// // The possible edges are:
-  /// // 0 -> 1
-  /// // 0 -> 2
-  /// // 1 -> 0
-  /// // 1 -> 2
-  /// // 2 -> 0
-  /// // 2 -> 1
-  /// assert_eq!(graph.max_number_of_edges(), 6);
+  /// let mut graph = Graph::<10>::new();
+  /// graph.add_edge(0, 1);
+  /// assert_eq!(graph.get_edge(0, 1), Some(true));
+  /// assert_eq!(graph.get_edge(1, 0), Some(false));
+  /// assert_eq!(graph.get_edge(0, 10), None);
   /// ```
-  pub const fn max_number_of_edges(&self) -> usize {
-    SIZE * (SIZE - 1)
+  /// See also [Graph::has_edge].
+  pub const fn get_edge(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> Option<bool> {
+    if i < SIZE && j < SIZE {
+      Some(self.data[i][j])
+    } else {
+      None
+    }
   }
 
-  /// Returns the density of a graph, that is, the ratio
-  /// between the number of edges and the maximum number of
-  /// possible edges.
+  /// Returns an iterator over the vertices `i` such that
+  /// there is an edge between `vertex` and `i`, in
+  /// ascending order.
   /// ```
   /// use const_graphs::Graph;
   ///
-  /// let mut graph = Graph::<3>::new();
-  /// graph.add_edge_undirected(0, 1);
-  /// graph.add_edge_undirected(0, 2);
-  /// graph.add_edge_undirected(1, 2);
-  /// assert_eq!(graph.density(), 1.0);
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 3);
+  /// assert!(graph.neighbors(0).eq([1, 3]));
+  /// assert!(graph.neighbors(0).rev().eq([3, 1]));
   /// ```
-  pub const fn density(&self) -> f32 {
-    let mut edges = 0;
-
+  /// See also [Graph::get_edges].
+  pub const fn neighbors(
+    &self,
+    vertex: usize,
+  ) -> Neighbors<'_, SIZE> {
+    Neighbors {
+      edges: &self.data[vertex],
+      start: 0,
+      end: SIZE,
+    }
+  }
+
+  /// Returns an iterator over the pairs `(i, j)` such that
+  /// there is an edge between `i` and `j`, in row-major
+  /// order.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(2, 0);
+  /// graph.add_edge(0, 1);
+  /// assert!(graph.edges().eq([(0, 1), (2, 0)]));
+  /// assert_eq!(graph.edges().count(), 2);
+  /// ```
+  /// See also [Graph::from_edges].
+  pub const fn edges(&self) -> Edges<'_, SIZE> {
+    Edges {
+      data: &self.data,
+      position: 0,
+    }
+  }
+
+  /// Returns the number of edges leaving `vertex`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// assert_eq!(graph.out_degree(0), 2);
+  /// assert_eq!(graph.out_degree(1), 0);
+  /// ```
+  /// See also [Graph::in_degree] and [Graph::degree].
+  pub const fn out_degree(&self, vertex: usize) -> usize {
+    let mut degree = 0;
+
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      if self.data[vertex][neighbor] {
+        degree += 1;
+      }
+
+      neighbor += 1;
+    }
+
+    degree
+  }
+
+  /// Returns the number of edges arriving at `vertex`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.in_degree(2), 2);
+  /// assert_eq!(graph.in_degree(0), 0);
+  /// ```
+  /// See also [Graph::out_degree] and [Graph::degree].
+  pub const fn in_degree(&self, vertex: usize) -> usize {
+    let mut degree = 0;
+
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      if self.data[neighbor][vertex] {
+        degree += 1;
+      }
+
+      neighbor += 1;
+    }
+
+    degree
+  }
+
+  /// Returns the sum of the in and out degrees of
+  /// `vertex`.
+  /// A self-loop is both an in and an out edge, so it is
+  /// counted twice.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(graph.degree(0), 3);
+  ///
+  /// graph.add_edge(2, 2);
+  /// assert_eq!(graph.degree(2), 3);
+  /// ```
+  /// See also [Graph::out_degree] and [Graph::in_degree].
+  pub const fn degree(&self, vertex: usize) -> usize {
+    self.out_degree(vertex) + self.in_degree(vertex)
+  }
+
+  /// Checks whether `vertex` has no edges from or to it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// assert!(!graph.is_isolated(0));
+  /// assert!(!graph.is_isolated(1));
+  /// assert!(graph.is_isolated(2));
+  /// ```
+  /// See also [Graph::degree].
+  pub const fn is_isolated(&self, vertex: usize) -> bool {
+    self.degree(vertex) == 0
+  }
+
+  /// Checks whether `vertex` is a source, that is, whether
+  /// it has edges from it but none to it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// assert!(graph.is_source(0));
+  /// assert!(!graph.is_source(1));
+  /// assert!(!graph.is_source(2));
+  /// ```
+  /// See also [Graph::is_sink].
+  pub const fn is_source(&self, vertex: usize) -> bool {
+    self.out_degree(vertex) > 0
+      && self.in_degree(vertex) == 0
+  }
+
+  /// Checks whether `vertex` is a sink, that is, whether it
+  /// has edges to it but none from it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// assert!(!graph.is_sink(0));
+  /// assert!(graph.is_sink(1));
+  /// assert!(!graph.is_sink(2));
+  /// ```
+  /// See also [Graph::is_source].
+  pub const fn is_sink(&self, vertex: usize) -> bool {
+    self.in_degree(vertex) > 0
+      && self.out_degree(vertex) == 0
+  }
+
+  /// Returns the largest out-degree of the vertices of the
+  /// graph, or 0 if it has no vertices.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.max_degree(), 2);
+  /// assert_eq!(Graph::<0>::new().max_degree(), 0);
+  /// ```
+  /// See also [Graph::out_degree] and [Graph::min_degree].
+  pub const fn max_degree(&self) -> usize {
+    let mut max = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let degree = self.out_degree(vertex);
+      if degree > max {
+        max = degree;
+      }
+
+      vertex += 1;
+    }
+
+    max
+  }
+
+  /// Returns the smallest out-degree of the vertices of the
+  /// graph, or 0 if it has no vertices.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.min_degree(), 0);
+  ///
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(graph.min_degree(), 1);
+  /// assert_eq!(Graph::<0>::new().min_degree(), 0);
+  /// ```
+  /// See also [Graph::out_degree] and [Graph::max_degree].
+  pub const fn min_degree(&self) -> usize {
+    if SIZE == 0 {
+      return 0;
+    }
+
+    let mut min = usize::MAX;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let degree = self.out_degree(vertex);
+      if degree < min {
+        min = degree;
+      }
+
+      vertex += 1;
+    }
+
+    min
+  }
+
+  /// Returns the degrees of the vertices sorted in
+  /// descending order.
+  /// The graph is treated as undirected, so each vertex
+  /// with an edge from or to the vertex is counted once,
+  /// and self-loops are ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 1);
+  /// graph.add_edge_undirected(1, 3);
+  /// graph.add_edge(3, 3);
+  /// assert_eq!(graph.degree_sequence(), [3, 1, 1, 1]);
+  /// ```
+  /// See also [Graph::is_graphical].
+  pub fn degree_sequence(&self) -> [usize; SIZE] {
+    let mut degrees = self.undirected_degrees();
+    degrees.sort_unstable_by(|a, b| b.cmp(a));
+    degrees
+  }
+
+  /// Checks whether `sequence` is the degree sequence of
+  /// some undirected graph without self-loops, using the
+  /// Erdős–Gallai theorem.
+  /// The sequence doesn't need to be sorted.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// assert!(Graph::is_graphical(&[1, 3, 1, 1]));
+  /// assert!(Graph::is_graphical(&[2, 2, 2]));
+  /// // The sum of the degrees must be even.
+  /// assert!(!Graph::is_graphical(&[2, 2, 1]));
+  /// assert!(!Graph::is_graphical(&[3, 3, 3, 1]));
+  /// ```
+  /// See also [Graph::degree_sequence].
+  pub fn is_graphical(sequence: &[usize; SIZE]) -> bool {
+    let mut degrees = *sequence;
+    degrees.sort_unstable_by(|a, b| b.cmp(a));
+
+    if degrees.iter().sum::<usize>() % 2 != 0 {
+      return false;
+    }
+
+    let mut sum = 0;
+    for k in 1..=SIZE {
+      sum += degrees[k - 1];
+
+      let rest: usize = degrees[k..]
+        .iter()
+        .map(|&degree| degree.min(k))
+        .sum();
+
+      if sum > k * (k - 1) + rest {
+        return false;
+      }
+    }
+
+    true
+  }
+
+  /// Returns the order of the graph, that is, its number of
+  /// vertices, which is always `SIZE`.
+  /// Not to be confused with [Graph::size], the number of
+  /// edges.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::new();
+  /// assert_eq!(graph.order(), 3);
+  /// ```
+  pub const fn order(&self) -> usize {
+    SIZE
+  }
+
+  /// Returns the size of the graph, that is, its number of
+  /// directed edges, just like [Graph::count_edges], so
+  /// self-loops are not counted.
+  /// Not to be confused with [Graph::order], the number of
+  /// vertices, which is what `SIZE` is.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.size(), 3);
+  /// assert_eq!(graph.order(), 3);
+  /// ```
+  pub const fn size(&self) -> usize {
+    self.count_edges()
+  }
+
+  /// Returns the maximum number of edges of a graph.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::new();
+  /// // The possible edges are:
+  /// // 0 -> 1
+  /// // 0 -> 2
+  /// // 1 -> 0
+  /// // 1 -> 2
+  /// // 2 -> 0
+  /// // 2 -> 1
+  /// assert_eq!(graph.max_number_of_edges(), 6);
+  /// ```
+  pub const fn max_number_of_edges(&self) -> usize {
+    SIZE * (SIZE - 1)
+  }
+
+  /// Returns the number of directed edges of a graph.
+  /// Just like in [Graph::max_number_of_edges],
+  /// self-loops are not counted.
+  /// The count is kept up to date as the edges change, so
+  /// this takes constant time, unless the edges were
+  /// changed through a mutable reference, like with
+  /// `graph[(i, j)] = true`, and not through a method since
+  /// then, see [Graph::recount_edges].
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 2);
+  /// assert_eq!(graph.count_edges(), 3);
+  ///
+  /// // Adding an edge again doesn't change anything.
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.count_edges(), 3);
+  ///
+  /// graph.remove_edge(1, 2);
+  /// graph.remove_edge(1, 2);
+  /// assert_eq!(graph.count_edges(), 2);
+  /// ```
+  pub const fn count_edges(&self) -> usize {
+    if self.edge_count_stale {
+      Graph::count_matrix_edges(&self.data)
+    } else {
+      self.edge_count
+    }
+  }
+
+  /// Counts the edges again, so [Graph::count_edges] takes
+  /// constant time again after the edges were changed
+  /// through a mutable reference.
+  /// Changing an edge through a method, like
+  /// [Graph::add_edge], does it too, so this is only
+  /// needed when the count is read before that.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph[(0, 1)] = true;
+  /// graph[(1, 2)] = true;
+  /// // Still right, but counted every time.
+  /// assert_eq!(graph.count_edges(), 2);
+  ///
+  /// graph.recount_edges();
+  /// assert_eq!(graph.count_edges(), 2);
+  ///
+  /// // Counted again once by `add_edge`.
+  /// graph[(2, 0)] = true;
+  /// graph.add_edge(0, 2);
+  /// assert_eq!(graph.count_edges(), 4);
+  /// ```
+  pub const fn recount_edges(&mut self) {
+    self.edge_count = Graph::count_matrix_edges(&self.data);
+    self.edge_count_stale = false;
+  }
+
+  /// Returns the density of a graph, that is, the ratio
+  /// between the number of edges and the maximum number of
+  /// possible edges.
+  /// Just like [Graph::count_edges], this takes constant
+  /// time.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(0, 2);
+  /// graph.add_edge_undirected(1, 2);
+  /// assert_eq!(graph.density(), 1.0);
+  /// ```
+  pub const fn density(&self) -> f32 {
+    self.count_edges() as f32
+      / self.max_number_of_edges() as f32
+  }
+
+  /// Checks whether the graph is complete, that is, whether
+  /// there is an edge between every `i` and `j` with
+  /// `i != j`, stopping at the first missing one.
+  /// Self-loops are ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::complete();
+  /// assert!(graph.is_complete());
+  ///
+  /// graph.remove_edge(2, 1);
+  /// assert!(!graph.is_complete());
+  /// ```
+  /// See also [Graph::complete].
+  pub const fn is_complete(&self) -> bool {
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if i != j && !self.data[i][j] {
+          return false;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    true
+  }
+
+  /// Checks whether the graph has no edges at all, not even
+  /// self-loops, stopping at the first one.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// assert!(graph.is_empty());
+  ///
+  /// graph.add_self_loop(1);
+  /// assert!(!graph.is_empty());
+  /// ```
+  pub const fn is_empty(&self) -> bool {
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if self.data[i][j] {
+          return false;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    true
+  }
+
+  /// Checks whether the graph is symmetric, that is, for
+  /// every edge between `i` and `j` there is also an edge
+  /// between `j` and `i`, so the graph can be seen as
+  /// undirected.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// assert!(graph.is_symmetric());
+  ///
+  /// graph.add_edge(1, 2);
+  /// assert!(!graph.is_symmetric());
+  /// ```
+  pub const fn is_symmetric(&self) -> bool {
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        if self.data[i][j] != self.data[j][i] {
+          return false;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    true
+  }
+
+  /// Returns a two-coloring of the graph, such that the
+  /// endpoints of every edge have different colors, or
+  /// `None` if there is none, that is, when the graph has
+  /// an odd cycle.
+  /// The graph is treated as undirected, and each connected
+  /// component is colored independently, starting with
+  /// `false` on its smallest vertex.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// assert_eq!(
+  ///   graph.is_bipartite(),
+  ///   Some([false, true, false, false])
+  /// );
+  ///
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(graph.is_bipartite(), None);
+  /// ```
+  pub const fn is_bipartite(&self) -> Option<[bool; SIZE]> {
+    let mut colors = [false; SIZE];
+    let mut visited = [false; SIZE];
+    let mut queue = [0; SIZE];
+
+    let mut start = 0;
+    while start < SIZE {
+      if !visited[start] {
+        visited[start] = true;
+
+        queue[0] = start;
+        let mut head = 0;
+        let mut tail = 1;
+
+        while head < tail {
+          let current = queue[head];
+          head += 1;
+
+          let mut neighbor = 0;
+          while neighbor < SIZE {
+            if self.has_edge_undirected(current, neighbor) {
+              if !visited[neighbor] {
+                visited[neighbor] = true;
+                colors[neighbor] = !colors[current];
+
+                queue[tail] = neighbor;
+                tail += 1;
+              } else if colors[neighbor] == colors[current]
+              {
+                return None;
+              }
+            }
+
+            neighbor += 1;
+          }
+        }
+      }
+
+      start += 1;
+    }
+
+    Some(colors)
+  }
+
+  /// Returns the degree of every vertex if they all have
+  /// the same one, or `None` otherwise.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored, so a graph without vertices is 0-regular.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<5>::cycle();
+  /// assert_eq!(graph.regular_degree(), Some(2));
+  /// let graph = Graph::<5>::star();
+  /// assert_eq!(graph.regular_degree(), None);
+  /// ```
+  /// See also [Graph::is_regular].
+  pub const fn regular_degree(&self) -> Option<usize> {
+    if SIZE == 0 {
+      return Some(0);
+    }
+
+    let degree = self.undirected_degree(0);
+
+    let mut vertex = 1;
+    while vertex < SIZE {
+      if self.undirected_degree(vertex) != degree {
+        return None;
+      }
+
+      vertex += 1;
+    }
+
+    Some(degree)
+  }
+
+  /// Checks whether every vertex has the same degree, just
+  /// like [Graph::regular_degree].
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::complete();
+  /// assert!(graph.is_regular());
+  ///
+  /// graph.remove_edge(0, 1);
+  /// assert!(graph.is_regular());
+  ///
+  /// graph.remove_edge(1, 0);
+  /// assert!(!graph.is_regular());
+  /// ```
+  pub const fn is_regular(&self) -> bool {
+    self.regular_degree().is_some()
+  }
+
+  /// Returns the number of vertices in each part if the
+  /// graph is a complete bipartite graph, that is, if its
+  /// vertices can be split in two non-empty parts with an
+  /// edge between every vertex of one and every vertex of
+  /// the other and no other edges, or `None` otherwise.
+  /// The graph is treated as undirected, and the first
+  /// part is the one with the vertex 0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<5>::star();
+  /// assert_eq!(
+  ///   graph.is_complete_bipartite(),
+  ///   Some((1, 4))
+  /// );
+  ///
+  /// let mut graph = Graph::<4>::cycle();
+  /// assert_eq!(
+  ///   graph.is_complete_bipartite(),
+  ///   Some((2, 2))
+  /// );
+  ///
+  /// graph.remove_edge(0, 1);
+  /// assert_eq!(
+  ///   graph.is_complete_bipartite(),
+  ///   Some((2, 2))
+  /// );
+  ///
+  /// graph.remove_edge(1, 0);
+  /// assert_eq!(
+  ///   graph.is_complete_bipartite(),
+  ///   None
+  /// );
+  /// ```
+  /// See also [Graph::is_bipartite].
+  pub const fn is_complete_bipartite(
+    &self,
+  ) -> Option<(usize, usize)> {
+    let colors = match self.is_bipartite() {
+      Some(colors) => colors,
+      None => return None,
+    };
+
+    let mut first = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      if !colors[i] {
+        first += 1;
+      }
+
+      let mut j = i + 1;
+      while j < SIZE {
+        if colors[i] != colors[j]
+          && !self.has_edge_undirected(i, j)
+        {
+          return None;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    if first == SIZE {
+      return None;
+    }
+
+    Some((first, SIZE - first))
+  }
+
+  /// Returns a coloring of the graph, such that the
+  /// endpoints of every edge have different colors, and the
+  /// number of colors used, numbered from 0.
+  /// The vertices are colored in descending order of their
+  /// degree, breaking ties by the smallest vertex, and each
+  /// one gets the smallest color not used by its neighbors,
+  /// so the result is always the same but not always
+  /// optimal.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::star();
+  /// graph.add_edge_undirected(1, 2);
+  /// assert_eq!(
+  ///   graph.greedy_coloring(),
+  ///   ([0, 1, 2, 1, 1], 3)
+  /// );
+  /// ```
+  /// See also [Graph::is_bipartite].
+  pub const fn greedy_coloring(
+    &self,
+  ) -> ([usize; SIZE], usize) {
+    let degrees = self.undirected_degrees();
+    let mut colors = [usize::MAX; SIZE];
+    let mut count = 0;
+
+    let mut step = 0;
+    while step < SIZE {
+      let mut current = usize::MAX;
+
+      let mut vertex = 0;
+      while vertex < SIZE {
+        if colors[vertex] == usize::MAX
+          && (current == usize::MAX
+            || degrees[vertex] > degrees[current])
+        {
+          current = vertex;
+        }
+
+        vertex += 1;
+      }
+
+      let mut used = [false; SIZE];
+
+      let mut neighbor = 0;
+      while neighbor < SIZE {
+        if neighbor != current
+          && colors[neighbor] != usize::MAX
+          && self.has_edge_undirected(current, neighbor)
+        {
+          used[colors[neighbor]] = true;
+        }
+
+        neighbor += 1;
+      }
+
+      let mut color = 0;
+      while used[color] {
+        color += 1;
+      }
+
+      colors[current] = color;
+      if color == count {
+        count += 1;
+      }
+
+      step += 1;
+    }
+
+    (colors, count)
+  }
+
+  /// Returns the id of the connected component of each
+  /// vertex and the number of components.
+  /// The graph is treated as undirected, and the
+  /// components are numbered from 0 in the order of their
+  /// smallest vertex.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(4, 1);
+  /// assert_eq!(
+  ///   graph.connected_components(),
+  ///   ([0, 1, 2, 0, 1], 3)
+  /// );
+  /// ```
+  pub const fn connected_components(
+    &self,
+  ) -> ([usize; SIZE], usize) {
+    let mut components = [usize::MAX; SIZE];
+    let mut count = 0;
+    let mut queue = [0; SIZE];
+
+    let mut start = 0;
+    while start < SIZE {
+      if components[start] == usize::MAX {
+        components[start] = count;
+
+        queue[0] = start;
+        let mut head = 0;
+        let mut tail = 1;
+
+        while head < tail {
+          let current = queue[head];
+          head += 1;
+
+          let mut neighbor = 0;
+          while neighbor < SIZE {
+            if components[neighbor] == usize::MAX
+              && self.has_edge_undirected(current, neighbor)
+            {
+              components[neighbor] = count;
+
+              queue[tail] = neighbor;
+              tail += 1;
+            }
+
+            neighbor += 1;
+          }
+        }
+
+        count += 1;
+      }
+
+      start += 1;
+    }
+
+    (components, count)
+  }
+
+  /// Checks whether every vertex can be reached from every
+  /// other vertex, treating the graph as undirected.
+  /// Graphs with 0 or 1 vertices are connected.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// assert!(!graph.is_connected());
+  ///
+  /// graph.add_edge(2, 1);
+  /// assert!(graph.is_connected());
+  /// ```
+  /// See also [Graph::connected_components].
+  pub const fn is_connected(&self) -> bool {
+    self.connected_components().1 <= 1
+  }
+
+  /// Returns the id of the weakly connected component of
+  /// each vertex and the number of components, where two
+  /// vertices are in the same component iff there is a path
+  /// between them when the direction of the edges is
+  /// ignored.
+  /// This is the same as [Graph::connected_components], and
+  /// unlike [Graph::strongly_connected_components], a
+  /// single edge is enough to join two components.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 1);
+  /// assert_eq!(
+  ///   graph.weakly_connected_components(),
+  ///   ([0, 0, 0, 1], 2)
+  /// );
+  /// let strong = graph.strongly_connected_components();
+  /// assert_eq!(strong.1, 4);
+  /// ```
+  pub const fn weakly_connected_components(
+    &self,
+  ) -> ([usize; SIZE], usize) {
+    self.connected_components()
+  }
+
+  /// Returns the id of the strongly connected component of
+  /// each vertex and the number of components, where two
+  /// vertices are in the same component iff each one can be
+  /// reached from the other.
+  /// Like in [Graph::connected_components], the components
+  /// are numbered from 0 in the order of their smallest
+  /// vertex.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 1);
+  /// graph.add_edge(3, 4);
+  /// graph.add_edge(4, 3);
+  /// graph.add_edge(4, 0);
+  /// assert_eq!(
+  ///   graph.strongly_connected_components(),
+  ///   ([0, 1, 1, 2, 2], 3)
+  /// );
+  /// ```
+  pub const fn strongly_connected_components(
+    &self,
+  ) -> ([usize; SIZE], usize) {
+    // Kosaraju's algorithm: first the vertices are sorted
+    // by the time a depth-first search finishes them.
+    let mut visited = [false; SIZE];
+    let mut finished = [0; SIZE];
+    let mut finished_len = 0;
+    let mut stack = [0; SIZE];
+    let mut next = [0; SIZE];
+
+    let mut start = 0;
+    while start < SIZE {
+      if !visited[start] {
+        visited[start] = true;
+        stack[0] = start;
+        next[0] = 0;
+        let mut len = 1;
+
+        while len > 0 {
+          let current = stack[len - 1];
+          let neighbor = next[len - 1];
+
+          if neighbor == SIZE {
+            finished[finished_len] = current;
+            finished_len += 1;
+            len -= 1;
+            continue;
+          }
+
+          next[len - 1] += 1;
+
+          if self.data[current][neighbor]
+            && !visited[neighbor]
+          {
+            visited[neighbor] = true;
+            stack[len] = neighbor;
+            next[len] = 0;
+            len += 1;
+          }
+        }
+      }
+
+      start += 1;
+    }
+
+    // Then, in the reverse of that order, each vertex not
+    // in a component yet gets a new one with every vertex
+    // that reaches it.
+    let mut found = [usize::MAX; SIZE];
+    let mut found_count = 0;
+
+    let mut i = SIZE;
+    while i > 0 {
+      i -= 1;
+      let root = finished[i];
+
+      if found[root] == usize::MAX {
+        found[root] = found_count;
+        stack[0] = root;
+        let mut len = 1;
+
+        while len > 0 {
+          len -= 1;
+          let current = stack[len];
+
+          let mut neighbor = 0;
+          while neighbor < SIZE {
+            if found[neighbor] == usize::MAX
+              && self.data[neighbor][current]
+            {
+              found[neighbor] = found_count;
+              stack[len] = neighbor;
+              len += 1;
+            }
+
+            neighbor += 1;
+          }
+        }
+
+        found_count += 1;
+      }
+    }
+
+    // Finally, the components are renumbered in the order
+    // of their smallest vertex.
+    let mut ids = [usize::MAX; SIZE];
+    let mut components = [0; SIZE];
+    let mut count = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if ids[found[vertex]] == usize::MAX {
+        ids[found[vertex]] = count;
+        count += 1;
+      }
+      components[vertex] = ids[found[vertex]];
+
+      vertex += 1;
+    }
+
+    (components, count)
+  }
+
+  /// Returns the condensation of the graph, where each
+  /// strongly connected component is contracted to the
+  /// vertex of its id, and the id of the component of each
+  /// vertex, as in [Graph::strongly_connected_components].
+  /// There is an edge between two components iff there is
+  /// one between any of their vertices, so the condensation
+  /// is acyclic and has no self-loops, and the vertices
+  /// that aren't the id of any component are isolated.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 0);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 3);
+  /// graph.add_edge(3, 2);
+  /// graph.add_edge(4, 3);
+  /// let (condensation, components) = graph.condensation();
+  /// assert_eq!(components, [0, 0, 1, 1, 2]);
+  /// assert!(condensation.has_edge(0, 1));
+  /// assert!(condensation.has_edge(2, 1));
+  /// assert_eq!(condensation.count_edges(), 2);
+  /// assert!(condensation.is_isolated(3));
+  /// assert!(condensation.is_isolated(4));
+  /// assert!(!condensation.has_cycle());
+  /// ```
+  /// See also [Graph::contract].
+  pub const fn condensation(
+    &self,
+  ) -> (Graph<SIZE>, [usize; SIZE]) {
+    let (components, _) =
+      self.strongly_connected_components();
+    let mut condensation = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if self.data[i][j] && components[i] != components[j]
+        {
+          condensation.set_edge_state(
+            components[i],
+            components[j],
+            true,
+          );
+        }
+
+        j += 1;
+      }
+
+      i += 1;
+    }
+
+    (condensation, components)
+  }
+
+  /// Returns, for each vertex, the time a depth-first
+  /// search of the undirected graph discovered it, its
+  /// low-link value, that is, the earliest discovery time
+  /// it reaches through its descendants and one back edge,
+  /// and its parent in the search, or `usize::MAX` for the
+  /// roots.
+  const fn low_links(
+    &self,
+  ) -> ([usize; SIZE], [usize; SIZE], [usize; SIZE]) {
+    let mut discovered = [usize::MAX; SIZE];
+    let mut low = [usize::MAX; SIZE];
+    let mut parents = [usize::MAX; SIZE];
+    let mut time = 0;
+    let mut stack = [0; SIZE];
+    let mut next = [0; SIZE];
+
+    let mut start = 0;
+    while start < SIZE {
+      if discovered[start] == usize::MAX {
+        discovered[start] = time;
+        low[start] = time;
+        time += 1;
+        stack[0] = start;
+        next[0] = 0;
+        let mut len = 1;
+
+        while len > 0 {
+          let current = stack[len - 1];
+          let neighbor = next[len - 1];
+
+          if neighbor == SIZE {
+            let parent = parents[current];
+            if parent != usize::MAX
+              && low[current] < low[parent]
+            {
+              low[parent] = low[current];
+            }
+
+            len -= 1;
+            continue;
+          }
+
+          next[len - 1] += 1;
+
+          if neighbor != current
+            && self.has_edge_undirected(current, neighbor)
+          {
+            if discovered[neighbor] == usize::MAX {
+              discovered[neighbor] = time;
+              low[neighbor] = time;
+              time += 1;
+              parents[neighbor] = current;
+              stack[len] = neighbor;
+              next[len] = 0;
+              len += 1;
+            } else if neighbor != parents[current]
+              && discovered[neighbor] < low[current]
+            {
+              low[current] = discovered[neighbor];
+            }
+          }
+        }
+      }
+
+      start += 1;
+    }
+
+    (discovered, low, parents)
+  }
+
+  /// Returns which vertices are articulation points, that
+  /// is, whose removal increases the number of connected
+  /// components.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge_undirected(2, 0);
+  /// graph.add_edge_undirected(2, 3);
+  /// assert_eq!(
+  ///   graph.articulation_points(),
+  ///   [false, false, true, false, false]
+  /// );
+  /// ```
+  /// See also [Graph::bridges].
+  pub const fn articulation_points(&self) -> [bool; SIZE] {
+    let (discovered, low, parents) = self.low_links();
+    let mut points = [false; SIZE];
+    let mut children = [0; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let parent = parents[vertex];
+
+      if parent != usize::MAX {
+        if parents[parent] == usize::MAX {
+          // A root is an articulation point iff it has
+          // more than one child.
+          children[parent] += 1;
+          if children[parent] > 1 {
+            points[parent] = true;
+          }
+        } else if low[vertex] >= discovered[parent] {
+          points[parent] = true;
+        }
+      }
+
+      vertex += 1;
+    }
+
+    points
+  }
+
+  /// Returns the bridges of the graph, that is, the edges
+  /// whose removal increases the number of connected
+  /// components, as pairs with the smallest vertex first,
+  /// in ascending order.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge_undirected(2, 0);
+  /// graph.add_edge(3, 2);
+  /// graph.add_edge_undirected(3, 4);
+  /// assert_eq!(graph.bridges(), vec![(2, 3), (3, 4)]);
+  /// ```
+  /// See also [Graph::articulation_points].
+  #[cfg(feature = "alloc")]
+  pub fn bridges(&self) -> Vec<(usize, usize)> {
+    let (discovered, low, parents) = self.low_links();
+
+    let mut bridges: Vec<_> = (0..SIZE)
+      .filter(|&vertex| {
+        parents[vertex] != usize::MAX
+          && low[vertex] > discovered[parents[vertex]]
+      })
+      .map(|vertex| {
+        let parent = parents[vertex];
+        (parent.min(vertex), parent.max(vertex))
+      })
+      .collect();
+    bridges.sort_unstable();
+
+    bridges
+  }
+
+  /// Returns the number of edges in the shortest path from
+  /// `start` to each vertex and the vertex before each one
+  /// in that path, or `usize::MAX` for both for the
+  /// vertices that can't be reached from `start`, and for
+  /// the predecessor of `start`.
+  const fn bfs(
+    &self,
+    start: usize,
+  ) -> ([usize; SIZE], [usize; SIZE]) {
+    let mut distances = [usize::MAX; SIZE];
+    let mut predecessors = [usize::MAX; SIZE];
+    let mut queue = [0; SIZE];
+
+    distances[start] = 0;
+    queue[0] = start;
+    let mut head = 0;
+    let mut tail = 1;
+
+    while head < tail {
+      let current = queue[head];
+      head += 1;
+
+      let mut neighbor = 0;
+      while neighbor < SIZE {
+        if distances[neighbor] == usize::MAX
+          && self.data[current][neighbor]
+        {
+          distances[neighbor] = distances[current] + 1;
+          predecessors[neighbor] = current;
+
+          queue[tail] = neighbor;
+          tail += 1;
+        }
+
+        neighbor += 1;
+      }
+    }
+
+    (distances, predecessors)
+  }
+
+  /// Returns the number of edges in the shortest path from
+  /// `start` to each vertex, found with a breadth-first
+  /// search, or `usize::MAX` for the vertices that can't
+  /// be reached from it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(3, 2);
+  /// assert_eq!(
+  ///   graph.bfs_distances(0),
+  ///   [0, 1, 2, 1, usize::MAX]
+  /// );
+  /// ```
+  /// See also [Graph::bfs_path].
+  pub const fn bfs_distances(
+    &self,
+    start: usize,
+  ) -> [usize; SIZE] {
+    self.bfs(start).0
+  }
+
+  /// Returns the number of edges in the shortest path
+  /// between each pair of vertices, that is,
+  /// [Graph::bfs_distances] from each vertex, with
+  /// `usize::MAX` when there is no path between them.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(
+  ///   graph.distance_matrix(),
+  ///   [
+  ///     [0, 1, 2],
+  ///     [usize::MAX, 0, 1],
+  ///     [usize::MAX, usize::MAX, 0],
+  ///   ]
+  /// );
+  /// ```
+  pub const fn distance_matrix(
+    &self,
+  ) -> [[usize; SIZE]; SIZE] {
+    let mut distances = [[usize::MAX; SIZE]; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      distances[vertex] = self.bfs(vertex).0;
+
+      vertex += 1;
+    }
+
+    distances
+  }
+
+  /// Returns the vertices of a shortest path from `start`
+  /// to `end`, including both, or `None` if `end` can't be
+  /// reached from `start`.
+  /// The neighbors are visited in ascending order, so the
+  /// same path is picked every time, and the path from a
+  /// vertex to itself is just that vertex.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(3, 2);
+  /// assert_eq!(graph.bfs_path(0, 2), Some(vec![0, 1, 2]));
+  /// assert_eq!(graph.bfs_path(2, 0), None);
+  /// assert_eq!(graph.bfs_path(4, 4), Some(vec![4]));
+  /// ```
+  /// See also [Graph::bfs_distances].
+  #[cfg(feature = "alloc")]
+  pub fn bfs_path(
+    &self,
+    start: usize,
+    end: usize,
+  ) -> Option<Vec<usize>> {
+    let (distances, predecessors) = self.bfs(start);
+    if distances[end] == usize::MAX {
+      return None;
+    }
+
+    let mut path = Vec::with_capacity(distances[end] + 1);
+    let mut current = end;
+    path.push(current);
+    while current != start {
+      current = predecessors[current];
+      path.push(current);
+    }
+    path.reverse();
+
+    Some(path)
+  }
+
+  /// Returns the vertices reachable from `start` in the
+  /// order a depth-first search discovers them and in the
+  /// order it finishes them, and how many there are.
+  /// The neighbors are visited in ascending order, with an
+  /// explicit stack instead of recursion.
+  #[cfg(feature = "alloc")]
+  const fn dfs(
+    &self,
+    start: usize,
+  ) -> ([usize; SIZE], [usize; SIZE], usize) {
+    let mut preorder = [0; SIZE];
+    let mut postorder = [0; SIZE];
+    let mut count = 0;
+    let mut finished = 0;
+    let mut visited = [false; SIZE];
+    let mut stack = [0; SIZE];
+    let mut next = [0; SIZE];
+
+    visited[start] = true;
+    preorder[0] = start;
+    count += 1;
+    stack[0] = start;
+    next[0] = 0;
+    let mut len = 1;
+
+    while len > 0 {
+      let current = stack[len - 1];
+      let neighbor = next[len - 1];
+
+      if neighbor == SIZE {
+        postorder[finished] = current;
+        finished += 1;
+        len -= 1;
+        continue;
+      }
+
+      next[len - 1] += 1;
+
+      if self.data[current][neighbor] && !visited[neighbor]
+      {
+        visited[neighbor] = true;
+        preorder[count] = neighbor;
+        count += 1;
+        stack[len] = neighbor;
+        next[len] = 0;
+        len += 1;
+      }
+    }
+
+    (preorder, postorder, count)
+  }
+
+  /// Returns the vertices reachable from `start`, including
+  /// it, in the order a depth-first search discovers them.
+  /// The neighbors are visited in ascending order, and an
+  /// explicit stack is used, so large graphs don't overflow
+  /// the call stack.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(graph.dfs_order(0), vec![0, 1, 2, 3]);
+  /// assert_eq!(graph.dfs_order(4), vec![4]);
+  /// ```
+  /// See also [Graph::dfs_postorder].
+  #[cfg(feature = "alloc")]
+  pub fn dfs_order(&self, start: usize) -> Vec<usize> {
+    let (preorder, _, count) = self.dfs(start);
+    preorder[..count].to_vec()
+  }
+
+  /// Returns the vertices reachable from `start`, including
+  /// it, in the order a depth-first search finishes them,
+  /// that is, each vertex comes after all the vertices
+  /// discovered from it.
+  /// Like [Graph::dfs_order], the neighbors are visited in
+  /// ascending order, with an explicit stack.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(graph.dfs_postorder(0), vec![2, 1, 3, 0]);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn dfs_postorder(&self, start: usize) -> Vec<usize> {
+    let (_, postorder, count) = self.dfs(start);
+    postorder[..count].to_vec()
+  }
+
+  /// Returns which vertices can be reached from `start`
+  /// through the directed edges, including `start` itself.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(3, 0);
+  /// assert_eq!(
+  ///   graph.reachable_from(0),
+  ///   [true, true, true, false]
+  /// );
+  /// ```
+  /// See also [Graph::is_reachable] and
+  /// [Graph::transitive_closure].
+  pub const fn reachable_from(
+    &self,
+    start: usize,
+  ) -> [bool; SIZE] {
+    let distances = self.bfs_distances(start);
+    let mut reachable = [false; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      reachable[vertex] = distances[vertex] != usize::MAX;
+
+      vertex += 1;
+    }
+
+    reachable
+  }
+
+  /// Returns which vertices can be reached from `vertex`
+  /// through the directed edges, like its dependents in a
+  /// dependency graph, excluding `vertex` itself even if it
+  /// is in a cycle.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(3, 1);
+  /// assert_eq!(
+  ///   graph.descendants(1),
+  ///   [false, false, true, false]
+  /// );
+  /// ```
+  /// See also [Graph::ancestors] and
+  /// [Graph::reachable_from].
+  pub const fn descendants(
+    &self,
+    vertex: usize,
+  ) -> [bool; SIZE] {
+    let mut descendants = self.reachable_from(vertex);
+    descendants[vertex] = false;
+    descendants
+  }
+
+  /// Returns which vertices can reach `vertex` through the
+  /// directed edges, like its dependencies in a dependency
+  /// graph, excluding `vertex` itself even if it is in a
+  /// cycle.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(3, 1);
+  /// assert_eq!(
+  ///   graph.ancestors(1),
+  ///   [true, false, false, true]
+  /// );
+  /// ```
+  /// See also [Graph::descendants].
+  pub const fn ancestors(
+    &self,
+    vertex: usize,
+  ) -> [bool; SIZE] {
+    let mut ancestors =
+      self.transpose().reachable_from(vertex);
+    ancestors[vertex] = false;
+    ancestors
+  }
+
+  /// Checks whether `to` can be reached from `from` through
+  /// the directed edges, stopping as soon as it is found.
+  /// Every vertex can reach itself.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// assert!(graph.is_reachable(0, 2));
+  /// assert!(!graph.is_reachable(2, 0));
+  /// assert!(graph.is_reachable(3, 3));
+  /// ```
+  /// See also [Graph::reachable_from].
+  pub const fn is_reachable(
+    &self,
+    from: usize,
+    to: usize,
+  ) -> bool {
+    if from == to {
+      return true;
+    }
+
+    let mut visited = [false; SIZE];
+    let mut queue = [0; SIZE];
+
+    visited[from] = true;
+    queue[0] = from;
+    let mut head = 0;
+    let mut tail = 1;
+
+    while head < tail {
+      let current = queue[head];
+      head += 1;
+
+      let mut neighbor = 0;
+      while neighbor < SIZE {
+        if !visited[neighbor]
+          && self.data[current][neighbor]
+        {
+          if neighbor == to {
+            return true;
+          }
+
+          visited[neighbor] = true;
+          queue[tail] = neighbor;
+          tail += 1;
+        }
+
+        neighbor += 1;
+      }
+    }
+
+    false
+  }
+
+  /// Returns every simple path from `start` to `end`, that
+  /// is, every path that doesn't repeat vertices, with at
+  /// most `max_length` edges if it is given.
+  /// The paths are found with a depth-first search that
+  /// visits the neighbors in ascending order, so they are
+  /// sorted, and the only path from a vertex to itself is
+  /// just that vertex.
+  ///
+  /// The number of paths can grow exponentially with the
+  /// number of vertices, and so can the time this takes,
+  /// so `max_length` should be used on large graphs.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(1, 3);
+  /// graph.add_edge(2, 3);
+  /// assert_eq!(
+  ///   graph.all_simple_paths(0, 3, None),
+  ///   vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 3]]
+  /// );
+  /// assert_eq!(
+  ///   graph.all_simple_paths(0, 3, Some(2)),
+  ///   vec![vec![0, 1, 3], vec![0, 2, 3]]
+  /// );
+  /// let paths = graph.all_simple_paths(3, 0, None);
+  /// assert!(paths.is_empty());
+  /// assert_eq!(graph.all_simple_paths(3, 3, None), [[3]]);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn all_simple_paths(
+    &self,
+    start: usize,
+    end: usize,
+    max_length: Option<usize>,
+  ) -> Vec<Vec<usize>> {
+    if start == end {
+      return Vec::from([Vec::from([start])]);
+    }
+
+    let max_length = max_length.unwrap_or(usize::MAX);
+    let mut paths = Vec::new();
+    let mut on_path = [false; SIZE];
+    // The current path, and the next neighbor to try from
+    // each of its vertices.
+    let mut path = Vec::from([start]);
+    let mut next = Vec::from([0]);
+    on_path[start] = true;
+
+    while let Some(&current) = path.last() {
+      let neighbor = (next[next.len() - 1]..SIZE)
+        .find(|&v| self.data[current][v] && !on_path[v]);
+
+      match neighbor {
+        // The path can only grow if it is shorter than the
+        // maximum length.
+        Some(neighbor) if path.len() <= max_length => {
+          *next.last_mut().unwrap() = neighbor + 1;
+
+          if neighbor == end {
+            let mut found = path.clone();
+            found.push(end);
+            paths.push(found);
+          } else {
+            on_path[neighbor] = true;
+            path.push(neighbor);
+            next.push(0);
+          }
+        }
+        _ => {
+          on_path[current] = false;
+          path.pop();
+          next.pop();
+        }
+      }
+    }
+
+    paths
+  }
+
+  /// Returns the eccentricity of `vertex`, that is, the
+  /// number of edges in the longest of the shortest paths
+  /// from it to the other vertices, or `None` if some
+  /// vertex can't be reached from it.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge(2, 3);
+  /// assert_eq!(graph.eccentricity(0), Some(3));
+  /// assert_eq!(graph.eccentricity(1), Some(2));
+  /// assert_eq!(graph.eccentricity(3), None);
+  /// ```
+  /// See also [Graph::diameter].
+  pub const fn eccentricity(
+    &self,
+    vertex: usize,
+  ) -> Option<usize> {
+    let distances = self.bfs_distances(vertex);
+    let mut eccentricity = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      if distances[i] == usize::MAX {
+        return None;
+      }
+      if distances[i] > eccentricity {
+        eccentricity = distances[i];
+      }
+
+      i += 1;
+    }
+
+    Some(eccentricity)
+  }
+
+  /// Returns the diameter of the graph, that is, the
+  /// largest eccentricity of its vertices, or `None` if
+  /// some vertex can't be reached from another one, which
+  /// is always the case when the graph is disconnected.
+  /// A graph without vertices has a diameter of 0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::path();
+  /// assert_eq!(graph.diameter(), Some(3));
+  ///
+  /// graph.remove_edge(2, 3);
+  /// assert_eq!(graph.diameter(), None);
+  ///
+  /// let graph = Graph::<4>::complete();
+  /// assert_eq!(graph.diameter(), Some(1));
+  /// ```
+  /// See also [Graph::eccentricity].
+  pub const fn diameter(&self) -> Option<usize> {
+    let mut diameter = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      match self.eccentricity(vertex) {
+        Some(eccentricity) if eccentricity > diameter => {
+          diameter = eccentricity;
+        }
+        Some(_) => {}
+        None => return None,
+      }
+
+      vertex += 1;
+    }
+
+    Some(diameter)
+  }
+
+  /// Returns the coreness of each vertex, that is, the
+  /// largest `k` such that the vertex is in the `k`-core
+  /// of the graph.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge_undirected(2, 0);
+  /// graph.add_edge_undirected(2, 3);
+  /// assert_eq!(graph.coreness(), [2, 2, 2, 1, 0]);
+  /// ```
+  /// See also [Graph::k_core].
+  pub const fn coreness(&self) -> [usize; SIZE] {
+    let mut degrees = self.undirected_degrees();
+    let mut removed = [false; SIZE];
+    let mut coreness = [0; SIZE];
+
+    // The vertices are removed in ascending order of their
+    // degree among the remaining ones.
+    let mut core = 0;
+    let mut step = 0;
+    while step < SIZE {
+      let mut lowest = usize::MAX;
+
+      let mut vertex = 0;
+      while vertex < SIZE {
+        if !removed[vertex]
+          && (lowest == usize::MAX
+            || degrees[vertex] < degrees[lowest])
+        {
+          lowest = vertex;
+        }
+
+        vertex += 1;
+      }
+
+      if degrees[lowest] > core {
+        core = degrees[lowest];
+      }
+      coreness[lowest] = core;
+      removed[lowest] = true;
+
+      let mut neighbor = 0;
+      while neighbor < SIZE {
+        if !removed[neighbor]
+          && self.has_edge_undirected(lowest, neighbor)
+        {
+          degrees[neighbor] -= 1;
+        }
+
+        neighbor += 1;
+      }
+
+      step += 1;
+    }
+
+    coreness
+  }
+
+  /// Returns which vertices are in the `k`-core of the
+  /// graph, that is, which ones remain after repeatedly
+  /// removing the vertices with less than `k` neighbors.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge_undirected(2, 0);
+  /// graph.add_edge_undirected(2, 3);
+  /// assert_eq!(
+  ///   graph.k_core(2),
+  ///   [true, true, true, false, false]
+  /// );
+  /// assert_eq!(graph.k_core(3), [false; 5]);
+  /// ```
+  /// See also [Graph::coreness].
+  pub const fn k_core(&self, k: usize) -> [bool; SIZE] {
+    let coreness = self.coreness();
+    let mut core = [false; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      core[vertex] = coreness[vertex] >= k;
+
+      vertex += 1;
+    }
+
+    core
+  }
+
+  /// Returns the degree centrality of each vertex, that is,
+  /// the fraction of the other vertices that are its
+  /// neighbors.
+  /// The graph is treated as undirected, self-loops are
+  /// ignored, and graphs with less than 2 vertices have a
+  /// centrality of 0.0 everywhere.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<5>::star();
+  /// assert_eq!(
+  ///   graph.degree_centrality(),
+  ///   [1.0, 0.25, 0.25, 0.25, 0.25]
+  /// );
+  /// ```
+  pub const fn degree_centrality(&self) -> [f32; SIZE] {
+    let mut centrality = [0.0; SIZE];
+    if SIZE < 2 {
+      return centrality;
+    }
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      centrality[vertex] = self.undirected_degree(vertex)
+        as f32
+        / (SIZE - 1) as f32;
+
+      vertex += 1;
+    }
+
+    centrality
+  }
+
+  /// Returns the PageRank of each vertex after
+  /// `iterations` steps of the power iteration, starting
+  /// from a uniform distribution.
+  /// At each step, every vertex gives `damping` of its
+  /// rank to its neighbors in equal parts and spreads the
+  /// rest over all the vertices, while the vertices without
+  /// neighbors spread all of it, so the ranks always add up
+  /// to 1.0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(1, 2);
+  /// let ranks = graph.pagerank(0.85, 50);
+  /// assert!(ranks[2] > ranks[0]);
+  /// assert_eq!(ranks[0], ranks[1]);
+  /// let total: f32 = ranks.iter().sum();
+  /// assert!((total - 1.0).abs() < 1e-6);
+  ///
+  /// let ranks = Graph::<4>::cycle().pagerank(0.85, 50);
+  /// assert_eq!(ranks, [0.25; 4]);
+  /// ```
+  pub const fn pagerank(
+    &self,
+    damping: f32,
+    iterations: usize,
+  ) -> [f32; SIZE] {
+    let mut out_degrees = [0; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      out_degrees[vertex] = self.out_degree(vertex);
+
+      vertex += 1;
+    }
+
+    let mut ranks = [1.0 / SIZE as f32; SIZE];
+
+    let mut iteration = 0;
+    while iteration < iterations {
+      // The rank of the vertices without neighbors is
+      // spread over all the vertices.
+      let mut dangling = 0.0;
+
+      let mut vertex = 0;
+      while vertex < SIZE {
+        if out_degrees[vertex] == 0 {
+          dangling += ranks[vertex];
+        }
+
+        vertex += 1;
+      }
+
+      let base =
+        (1.0 - damping + damping * dangling) / SIZE as f32;
+      let mut next = [base; SIZE];
+
+      let mut i = 0;
+      while i < SIZE {
+        if out_degrees[i] != 0 {
+          let share =
+            damping * ranks[i] / out_degrees[i] as f32;
+
+          let mut j = 0;
+          while j < SIZE {
+            if self.data[i][j] {
+              next[j] += share;
+            }
+
+            j += 1;
+          }
+        }
+
+        i += 1;
+      }
+
+      ranks = next;
+      iteration += 1;
+    }
+
+    ranks
+  }
+
+  /// Returns the closeness centrality of each vertex, that
+  /// is, the inverse of the average number of edges in the
+  /// shortest paths from it to the other vertices.
+  /// Only the vertices that can be reached are averaged,
+  /// so the vertices that reach no other have a centrality
+  /// of 0.0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge(3, 0);
+  /// assert_eq!(
+  ///   graph.closeness_centrality(),
+  ///   [2.0 / 3.0, 1.0, 2.0 / 3.0, 0.5]
+  /// );
+  /// ```
+  /// See also [Graph::harmonic_centrality].
+  pub const fn closeness_centrality(&self) -> [f32; SIZE] {
+    let mut centrality = [0.0; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let (distances, _) = self.bfs(vertex);
+      let mut reachable = 0;
+      let mut total = 0;
+
+      let mut other = 0;
+      while other < SIZE {
+        if other != vertex && distances[other] != usize::MAX
+        {
+          reachable += 1;
+          total += distances[other];
+        }
+
+        other += 1;
+      }
+
+      if reachable > 0 {
+        centrality[vertex] =
+          reachable as f32 / total as f32;
+      }
+
+      vertex += 1;
+    }
+
+    centrality
+  }
+
+  /// Returns the harmonic centrality of each vertex, that
+  /// is, the sum of the inverses of the number of edges in
+  /// the shortest paths from it to the other vertices.
+  /// The vertices that can't be reached add 0.0, so unlike
+  /// [Graph::closeness_centrality] it works the same for
+  /// disconnected graphs.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge(3, 0);
+  /// assert_eq!(
+  ///   graph.harmonic_centrality(),
+  ///   [1.5, 2.0, 1.5, 1.0 + 0.5 + 1.0 / 3.0]
+  /// );
+  /// ```
+  pub const fn harmonic_centrality(&self) -> [f32; SIZE] {
+    let mut centrality = [0.0; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let (distances, _) = self.bfs(vertex);
+
+      let mut other = 0;
+      while other < SIZE {
+        if other != vertex && distances[other] != usize::MAX
+        {
+          centrality[vertex] +=
+            1.0 / distances[other] as f32;
+        }
+
+        other += 1;
+      }
+
+      vertex += 1;
+    }
+
+    centrality
+  }
+
+  /// Returns the betweenness centrality of each vertex,
+  /// that is, the sum over the ordered pairs of other
+  /// vertices of the fraction of the shortest paths between
+  /// them that pass through it, using Brandes' algorithm.
+  /// Both directions of each pair are counted, so in an
+  /// undirected graph the scores are twice the ones of
+  /// counting each path once.
+  /// If `normalized` is true, the scores are divided by the
+  /// number of ordered pairs of other vertices,
+  /// `(SIZE - 1) * (SIZE - 2)`, so they are between 0.0 and
+  /// 1.0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<5>::star();
+  /// assert_eq!(
+  ///   graph.betweenness_centrality(false),
+  ///   [12.0, 0.0, 0.0, 0.0, 0.0]
+  /// );
+  /// assert_eq!(
+  ///   graph.betweenness_centrality(true),
+  ///   [1.0, 0.0, 0.0, 0.0, 0.0]
+  /// );
+  ///
+  /// // Half of the shortest paths between 0 and 3 pass
+  /// // through each of 1 and 2.
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(1, 3);
+  /// graph.add_edge(2, 3);
+  /// assert_eq!(
+  ///   graph.betweenness_centrality(false),
+  ///   [0.0, 0.5, 0.5, 0.0]
+  /// );
+  /// ```
+  /// See also [Graph::closeness_centrality].
+  pub const fn betweenness_centrality(
+    &self,
+    normalized: bool,
+  ) -> [f32; SIZE] {
+    let mut centrality = [0.0; SIZE];
+
+    let mut source = 0;
+    while source < SIZE {
+      // The vertices are visited in a breadth-first order,
+      // counting the shortest paths from the source to
+      // each one.
+      let mut distances = [usize::MAX; SIZE];
+      let mut paths = [0.0f32; SIZE];
+      let mut queue = [0; SIZE];
+
+      distances[source] = 0;
+      paths[source] = 1.0;
+      queue[0] = source;
+      let mut head = 0;
+      let mut tail = 1;
+
+      while head < tail {
+        let current = queue[head];
+        head += 1;
+
+        let mut neighbor = 0;
+        while neighbor < SIZE {
+          if self.data[current][neighbor] {
+            if distances[neighbor] == usize::MAX {
+              distances[neighbor] = distances[current] + 1;
+              queue[tail] = neighbor;
+              tail += 1;
+            }
+            if distances[neighbor] == distances[current] + 1
+            {
+              paths[neighbor] += paths[current];
+            }
+          }
+
+          neighbor += 1;
+        }
+      }
+
+      // Then, from the farthest ones, each vertex passes
+      // its dependency to its predecessors in proportion to
+      // their numbers of paths.
+      let mut dependencies = [0.0f32; SIZE];
+
+      while tail > 1 {
+        tail -= 1;
+        let current = queue[tail];
+
+        let mut predecessor = 0;
+        while predecessor < SIZE {
+          if self.data[predecessor][current]
+            && distances[predecessor] != usize::MAX
+            && distances[predecessor] + 1
+              == distances[current]
+          {
+            dependencies[predecessor] += paths[predecessor]
+              / paths[current]
+              * (1.0 + dependencies[current]);
+          }
+
+          predecessor += 1;
+        }
+
+        centrality[current] += dependencies[current];
+      }
+
+      source += 1;
+    }
+
+    if normalized && SIZE > 2 {
+      let pairs = ((SIZE - 1) * (SIZE - 2)) as f32;
+
+      let mut vertex = 0;
+      while vertex < SIZE {
+        centrality[vertex] /= pairs;
+
+        vertex += 1;
+      }
+    }
+
+    centrality
+  }
+
+  /// Returns the number of pairs of distinct vertices with
+  /// an edge between them in any direction, used by the
+  /// algorithms that treat the graph as undirected.
+  const fn count_undirected_edges(&self) -> usize {
+    let mut count = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        if self.has_edge_undirected(i, j) {
+          count += 1;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    count
+  }
+
+  /// Checks whether the graph is a forest, that is,
+  /// whether it has no cycles when treated as undirected.
+  /// A self-loop is a cycle.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 1);
+  /// assert!(graph.is_forest());
+  ///
+  /// graph.add_edge(0, 2);
+  /// assert!(!graph.is_forest());
+  /// ```
+  /// See also [Graph::is_tree].
+  pub const fn is_forest(&self) -> bool {
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if self.data[vertex][vertex] {
+        return false;
+      }
+
+      vertex += 1;
+    }
+
+    // Each edge of a forest joins two of its trees.
+    self.count_undirected_edges()
+      == SIZE - self.connected_components().1
+  }
+
+  /// Checks whether the graph is a tree, that is, whether
+  /// it is connected and has no cycles when treated as
+  /// undirected, so it has `SIZE - 1` edges.
+  /// A graph without vertices is not a tree.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::star();
+  /// assert!(graph.is_tree());
+  ///
+  /// graph.remove_edge_undirected(0, 3);
+  /// assert!(!graph.is_tree());
+  /// assert!(graph.is_forest());
+  /// ```
+  /// See also [Graph::is_forest].
+  pub const fn is_tree(&self) -> bool {
+    SIZE > 0
+      && self.count_undirected_edges() == SIZE - 1
+      && self.is_forest()
+  }
+
+  /// Returns the girth of the graph, that is, the number of
+  /// edges in its shortest cycle, or `None` if it has no
+  /// cycles.
+  /// The graph is treated as undirected, so an edge in both
+  /// directions is not a cycle, but a self-loop is one of
+  /// length 1.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::cycle();
+  /// assert_eq!(graph.girth(), Some(5));
+  ///
+  /// graph.add_edge(3, 1);
+  /// assert_eq!(graph.girth(), Some(3));
+  ///
+  /// graph.add_self_loop(4);
+  /// assert_eq!(graph.girth(), Some(1));
+  ///
+  /// assert_eq!(Graph::<5>::star().girth(), None);
+  /// ```
+  /// See also [Graph::is_forest].
+  pub const fn girth(&self) -> Option<usize> {
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if self.data[vertex][vertex] {
+        return Some(1);
+      }
+
+      vertex += 1;
+    }
+
+    let mut girth = usize::MAX;
+    let mut queue = [0; SIZE];
+
+    // A breadth-first search from each vertex finds the
+    // shortest cycle through it when it reaches a vertex
+    // that was already reached through another path.
+    let mut start = 0;
+    while start < SIZE {
+      let mut distances = [usize::MAX; SIZE];
+      let mut parents = [usize::MAX; SIZE];
+
+      distances[start] = 0;
+      queue[0] = start;
+      let mut head = 0;
+      let mut tail = 1;
+
+      while head < tail {
+        let current = queue[head];
+        head += 1;
+
+        let mut neighbor = 0;
+        while neighbor < SIZE {
+          if neighbor != parents[current]
+            && self
+              .is_undirected_neighbor(current, neighbor)
+          {
+            if distances[neighbor] == usize::MAX {
+              distances[neighbor] = distances[current] + 1;
+              parents[neighbor] = current;
+
+              queue[tail] = neighbor;
+              tail += 1;
+            } else {
+              let length = distances[current]
+                + distances[neighbor]
+                + 1;
+              if length < girth {
+                girth = length;
+              }
+            }
+          }
+
+          neighbor += 1;
+        }
+      }
+
+      start += 1;
+    }
+
+    if girth == usize::MAX {
+      None
+    } else {
+      Some(girth)
+    }
+  }
+
+  /// Checks whether the directed graph has a cycle, that
+  /// is, a path from a vertex to itself.
+  /// A self-loop is a cycle.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(0, 2);
+  /// assert!(!graph.has_cycle());
+  ///
+  /// graph.add_edge(2, 0);
+  /// assert!(graph.has_cycle());
+  ///
+  /// let mut graph = Graph::<1>::new();
+  /// graph.add_edge(0, 0);
+  /// assert!(graph.has_cycle());
+  /// ```
+  pub const fn has_cycle(&self) -> bool {
+    // Not visited yet.
+    const WHITE: u8 = 0;
+    // In the current path.
+    const GRAY: u8 = 1;
+    // Finished, with all its descendants.
+    const BLACK: u8 = 2;
+
+    let mut colors = [WHITE; SIZE];
+    // The current path, and the next neighbor to visit from
+    // each of its vertices.
+    let mut stack = [0; SIZE];
+    let mut next = [0; SIZE];
+
+    let mut start = 0;
+    while start < SIZE {
+      if colors[start] == WHITE {
+        colors[start] = GRAY;
+        stack[0] = start;
+        next[0] = 0;
+        let mut len = 1;
+
+        while len > 0 {
+          let current = stack[len - 1];
+          let neighbor = next[len - 1];
+
+          if neighbor == SIZE {
+            colors[current] = BLACK;
+            len -= 1;
+            continue;
+          }
+
+          next[len - 1] += 1;
+
+          if self.data[current][neighbor] {
+            if colors[neighbor] == GRAY {
+              return true;
+            }
+
+            if colors[neighbor] == WHITE {
+              colors[neighbor] = GRAY;
+              stack[len] = neighbor;
+              next[len] = 0;
+              len += 1;
+            }
+          }
+        }
+      }
+
+      start += 1;
+    }
+
+    false
+  }
+
+  /// Checks whether all the vertices with a positive degree
+  /// in `degrees` are in the same connected component.
+  const fn has_one_nontrivial_component(
+    &self,
+    degrees: &[usize; SIZE],
+  ) -> bool {
+    let (components, _) = self.connected_components();
+    let mut component = usize::MAX;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if degrees[vertex] > 0 {
+        if component == usize::MAX {
+          component = components[vertex];
+        } else if components[vertex] != component {
+          return false;
+        }
+      }
+
+      vertex += 1;
+    }
+
+    true
+  }
+
+  /// Returns the difference between the out-degree and the
+  /// in-degree of each vertex and the degree of each one.
+  const fn degree_balances(
+    &self,
+  ) -> ([isize; SIZE], [usize; SIZE]) {
+    let mut balances = [0; SIZE];
+    let mut degrees = [0; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let out_degree = self.out_degree(vertex);
+      let in_degree = self.in_degree(vertex);
+      balances[vertex] =
+        out_degree as isize - in_degree as isize;
+      degrees[vertex] = out_degree + in_degree;
+
+      vertex += 1;
+    }
+
+    (balances, degrees)
+  }
+
+  /// Checks whether the directed graph has an Eulerian
+  /// circuit, that is, a closed walk that goes through
+  /// every edge exactly once.
+  /// This is the case iff every vertex has the same in- and
+  /// out-degree and all the edges are connected.
+  /// A graph without edges has an empty circuit.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// assert!(!graph.has_eulerian_circuit());
+  ///
+  /// graph.add_edge(2, 0);
+  /// graph.add_self_loop(1);
+  /// assert!(graph.has_eulerian_circuit());
+  /// ```
+  /// See also [Graph::has_eulerian_circuit_undirected].
+  pub const fn has_eulerian_circuit(&self) -> bool {
+    let (balances, degrees) = self.degree_balances();
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if balances[vertex] != 0 {
+        return false;
+      }
+
+      vertex += 1;
+    }
+
+    self.has_one_nontrivial_component(&degrees)
+  }
+
+  /// Checks whether the directed graph has an Eulerian
+  /// path, that is, a walk that goes through every edge
+  /// exactly once.
+  /// This is the case iff all the edges are connected and
+  /// every vertex has the same in- and out-degree, except
+  /// perhaps for the start, with one more out-edge, and the
+  /// end, with one more in-edge.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// assert!(graph.has_eulerian_path());
+  ///
+  /// graph.add_edge(3, 2);
+  /// assert!(!graph.has_eulerian_path());
+  /// ```
+  /// See also [Graph::eulerian_path] and
+  /// [Graph::has_eulerian_path_undirected].
+  pub const fn has_eulerian_path(&self) -> bool {
+    let (balances, degrees) = self.degree_balances();
+    let mut starts = 0;
+    let mut ends = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      match balances[vertex] {
+        0 => {}
+        1 => starts += 1,
+        -1 => ends += 1,
+        _ => return false,
+      }
+
+      vertex += 1;
+    }
+
+    starts <= 1
+      && ends <= 1
+      && self.has_one_nontrivial_component(&degrees)
+  }
+
+  /// Checks whether the graph, treated as undirected, has
+  /// an Eulerian circuit, that is, a closed walk that goes
+  /// through every edge exactly once.
+  /// This is the case iff every vertex has an even number
+  /// of neighbors and all the edges are connected.
+  /// Self-loops are ignored, and a graph without edges has
+  /// an empty circuit.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::cycle();
+  /// assert!(graph.has_eulerian_circuit_undirected());
+  ///
+  /// graph.add_edge(0, 2);
+  /// assert!(!graph.has_eulerian_circuit_undirected());
+  /// ```
+  /// See also [Graph::has_eulerian_circuit].
+  pub const fn has_eulerian_circuit_undirected(
+    &self,
+  ) -> bool {
+    let degrees = self.undirected_degrees();
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if !degrees[vertex].is_multiple_of(2) {
+        return false;
+      }
+
+      vertex += 1;
+    }
+
+    self.has_one_nontrivial_component(&degrees)
+  }
+
+  /// Checks whether the graph, treated as undirected, has
+  /// an Eulerian path, that is, a walk that goes through
+  /// every edge exactly once.
+  /// This is the case iff all the edges are connected and
+  /// at most two vertices, the endpoints of the path, have
+  /// an odd number of neighbors.
+  /// Self-loops are ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::cycle();
+  /// graph.add_edge(0, 2);
+  /// assert!(graph.has_eulerian_path_undirected());
+  ///
+  /// graph.add_edge(1, 3);
+  /// assert!(!graph.has_eulerian_path_undirected());
+  /// ```
+  /// See also [Graph::has_eulerian_path].
+  pub const fn has_eulerian_path_undirected(&self) -> bool {
+    let degrees = self.undirected_degrees();
+    let mut odd = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if !degrees[vertex].is_multiple_of(2) {
+        odd += 1;
+      }
+
+      vertex += 1;
+    }
+
+    odd <= 2 && self.has_one_nontrivial_component(&degrees)
+  }
+
+  /// Returns the vertices of an Eulerian path of the
+  /// directed graph, that is, of a walk that goes through
+  /// every edge exactly once, or `None` if there is none.
+  /// It starts at the vertex with one more out-edge than
+  /// in-edges, if any, or else at the smallest vertex with
+  /// an edge, and the edges are taken in ascending order
+  /// with Hierholzer's algorithm.
+  /// A graph without edges has an empty path.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 0);
+  /// graph.add_edge(0, 3);
+  /// assert_eq!(
+  ///   graph.eulerian_path(),
+  ///   Some(vec![0, 1, 2, 0, 3])
+  /// );
+  ///
+  /// graph.add_edge(1, 3);
+  /// assert_eq!(graph.eulerian_path(), None);
+  /// ```
+  /// See also [Graph::has_eulerian_path].
+  #[cfg(feature = "alloc")]
+  pub fn eulerian_path(&self) -> Option<Vec<usize>> {
+    if !self.has_eulerian_path() {
+      return None;
+    }
+
+    let (balances, degrees) = self.degree_balances();
+    let start = match (0..SIZE).find(|&v| balances[v] == 1)
+    {
+      Some(start) => start,
+      None => match (0..SIZE).find(|&v| degrees[v] > 0) {
+        Some(start) => start,
+        None => return Some(Vec::new()),
+      },
+    };
+
+    // The next neighbor to try from each vertex, so each
+    // edge is only taken once.
+    let mut next = [0; SIZE];
+    let mut path = Vec::new();
+    let mut stack = Vec::from([start]);
+
+    while let Some(&current) = stack.last() {
+      match (next[current]..SIZE)
+        .find(|&neighbor| self.data[current][neighbor])
+      {
+        Some(neighbor) => {
+          next[current] = neighbor + 1;
+          stack.push(neighbor);
+        }
+        None => {
+          next[current] = SIZE;
+          path.push(current);
+          stack.pop();
+        }
+      }
+    }
+
+    path.reverse();
+    Some(path)
+  }
+
+  /// Returns the vertices in an order such that every edge
+  /// goes from a vertex to a later one, or `None` if there
+  /// is no such order, that is, when the graph has a cycle.
+  /// When more than one vertex could come next, the
+  /// smallest one is picked, so the order is always the
+  /// same.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(3, 1);
+  /// graph.add_edge(1, 0);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(
+  ///   graph.topological_sort(),
+  ///   Some(vec![2, 3, 1, 0])
+  /// );
+  ///
+  /// graph.add_edge(0, 3);
+  /// assert_eq!(graph.topological_sort(), None);
+  /// ```
+  /// See also [Graph::has_cycle].
+  #[cfg(feature = "alloc")]
+  pub fn topological_sort(&self) -> Option<Vec<usize>> {
+    let mut in_degrees = [0; SIZE];
+    for (vertex, in_degree) in
+      in_degrees.iter_mut().enumerate()
+    {
+      *in_degree = self.in_degree(vertex);
+    }
+
+    let mut sorted = [false; SIZE];
+    let mut order = Vec::with_capacity(SIZE);
+
+    while order.len() < SIZE {
+      let current = (0..SIZE).find(|&vertex| {
+        !sorted[vertex] && in_degrees[vertex] == 0
+      })?;
+
+      sorted[current] = true;
+      order.push(current);
+
+      for neighbor in self.neighbors(current) {
+        in_degrees[neighbor] -= 1;
+      }
+    }
+
+    Some(order)
+  }
+
+  /// Returns a new graph with an edge between `i` and `j`
+  /// iff `j` can be reached from `i` through a path of one
+  /// or more edges.
+  /// A vertex only reaches itself if it is in a cycle, so
+  /// the diagonal of the result tells which vertices are.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 1);
+  /// let closure = graph.transitive_closure();
+  /// assert!(closure.has_edge(0, 2));
+  /// assert!(!closure.has_edge(2, 0));
+  /// assert!(!closure.has_edge(0, 0));
+  /// assert!(closure.has_edge(1, 1));
+  /// assert!(!closure.has_edge(0, 3));
+  /// ```
+  pub const fn transitive_closure(&self) -> Graph<SIZE> {
+    let mut closure = *self;
+
+    let mut k = 0;
+    while k < SIZE {
+      let mut i = 0;
+      while i < SIZE {
+        if closure.data[i][k] {
+          let mut j = 0;
+          while j < SIZE {
+            if closure.data[k][j] {
+              closure.set_edge_state(i, j, true);
+            }
+
+            j += 1;
+          }
+        }
+        i += 1;
+      }
+      k += 1;
+    }
+
+    closure
+  }
+
+  /// Returns a matrix where `walks[i][j]` is the number of
+  /// walks of exactly `k` edges between `i` and `j`, that
+  /// is, the `k`th power of the adjacency matrix.
+  /// The counts saturate at `u64::MAX` instead of
+  /// overflowing.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge_undirected(1, 2);
+  /// let walks = graph.walks_of_length(2);
+  /// // 0 -> 1 -> 0 and 2 -> 1 -> 0.
+  /// assert_eq!(walks[0][0], 1);
+  /// assert_eq!(walks[2][0], 1);
+  /// // 1 -> 0 -> 1 and 1 -> 2 -> 1.
+  /// assert_eq!(walks[1][1], 2);
+  /// assert_eq!(walks[0][1], 0);
+  ///
+  /// // Every vertex has a single walk of length 0.
+  /// assert_eq!(graph.walks_of_length(0)[1], [0, 1, 0]);
+  /// ```
+  pub const fn walks_of_length(
+    &self,
+    k: usize,
+  ) -> [[u64; SIZE]; SIZE] {
+    let mut walks = [[0; SIZE]; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      walks[i][i] = 1;
+
+      i += 1;
+    }
+
+    let mut length = 0;
+    while length < k {
+      let mut longer = [[0u64; SIZE]; SIZE];
+
+      let mut i = 0;
+      while i < SIZE {
+        let mut middle = 0;
+        while middle < SIZE {
+          if walks[i][middle] != 0 {
+            let mut j = 0;
+            while j < SIZE {
+              if self.data[middle][j] {
+                longer[i][j] = longer[i][j]
+                  .saturating_add(walks[i][middle]);
+              }
+
+              j += 1;
+            }
+          }
+
+          middle += 1;
+        }
+        i += 1;
+      }
+
+      walks = longer;
+      length += 1;
+    }
+
+    walks
+  }
+
+  /// Returns the number of triangles in the graph, that
+  /// is, of sets of 3 distinct vertices with an edge
+  /// between each pair of them.
+  /// The graph is treated as undirected, so the direction
+  /// of the edges doesn't matter, and each triangle is
+  /// counted once, not once per vertex or per orientation.
+  /// Self-loops are ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 0);
+  /// graph.add_edge(0, 2);
+  /// assert_eq!(graph.count_triangles(), 1);
+  ///
+  /// let complete = Graph::<4>::complete();
+  /// assert_eq!(complete.count_triangles(), 4);
+  /// ```
+  pub const fn count_triangles(&self) -> usize {
+    let mut count = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        if self.has_edge_undirected(i, j) {
+          let mut k = j + 1;
+          while k < SIZE {
+            if self.has_edge_undirected(i, k)
+              && self.has_edge_undirected(j, k)
+            {
+              count += 1;
+            }
+
+            k += 1;
+          }
+        }
+
+        j += 1;
+      }
+
+      i += 1;
+    }
+
+    count
+  }
+
+  /// Checks whether `neighbor` is a neighbor of `vertex`
+  /// when the graph is treated as undirected, which
+  /// excludes `vertex` itself.
+  const fn is_undirected_neighbor(
+    &self,
+    vertex: usize,
+    neighbor: usize,
+  ) -> bool {
+    neighbor != vertex
+      && self.has_edge_undirected(vertex, neighbor)
+  }
+
+  /// Returns the vertices that are neighbors of both `i`
+  /// and `j`, in ascending order.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(4, 0);
+  /// graph.add_edge(2, 1);
+  /// graph.add_edge(1, 4);
+  /// assert_eq!(graph.common_neighbors(0, 1), vec![2, 4]);
+  /// ```
+  /// See also [Graph::jaccard_similarity].
+  #[cfg(feature = "alloc")]
+  pub fn common_neighbors(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> Vec<usize> {
+    (0..SIZE)
+      .filter(|&k| {
+        self.is_undirected_neighbor(i, k)
+          && self.is_undirected_neighbor(j, k)
+      })
+      .collect()
+  }
+
+  /// Returns the Jaccard similarity of the neighborhoods of
+  /// `i` and `j`, that is, the number of their common
+  /// neighbors over the number of vertices that are a
+  /// neighbor of any of them.
+  /// The graph is treated as undirected, self-loops are
+  /// ignored, and the similarity is 0.0 when none of them
+  /// has neighbors.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(0, 3);
+  /// graph.add_edge(4, 0);
+  /// graph.add_edge(2, 1);
+  /// graph.add_edge(1, 4);
+  /// assert_eq!(graph.jaccard_similarity(0, 1), 2.0 / 3.0);
+  /// assert_eq!(graph.jaccard_similarity(2, 3), 0.5);
+  /// ```
+  /// See also [Graph::common_neighbors].
+  pub const fn jaccard_similarity(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> f32 {
+    let mut intersection = 0;
+    let mut union = 0;
+
+    let mut k = 0;
+    while k < SIZE {
+      let in_i = self.is_undirected_neighbor(i, k);
+      let in_j = self.is_undirected_neighbor(j, k);
+
+      if in_i && in_j {
+        intersection += 1;
+      }
+      if in_i || in_j {
+        union += 1;
+      }
+
+      k += 1;
+    }
+
+    if union == 0 {
+      return 0.0;
+    }
+
+    intersection as f32 / union as f32
+  }
+
+  /// Extends `clique` with the vertices of `candidates`,
+  /// none of `excluded`, keeping the largest clique found
+  /// in `largest`, using the Bron-Kerbosch algorithm with
+  /// pivoting.
+  #[cfg(feature = "alloc")]
+  fn bron_kerbosch(
+    &self,
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    largest: &mut Vec<usize>,
+  ) {
+    if candidates.is_empty() {
+      if excluded.is_empty() && clique.len() > largest.len()
+      {
+        largest.clone_from(clique);
+      }
+      return;
+    }
+
+    // Only the candidates that aren't neighbors of the
+    // pivot need to be tried, the others are found with it.
+    let pivot = candidates
+      .iter()
+      .chain(&excluded)
+      .copied()
+      .max_by_key(|&pivot| {
+        candidates
+          .iter()
+          .filter(|&&v| {
+            self.is_undirected_neighbor(pivot, v)
+          })
+          .count()
+      })
+      .unwrap_or(candidates[0]);
+
+    let tried: Vec<_> = candidates
+      .iter()
+      .copied()
+      .filter(|&v| !self.is_undirected_neighbor(pivot, v))
+      .collect();
+
+    for vertex in tried {
+      let neighbors = |set: &[usize]| -> Vec<usize> {
+        set
+          .iter()
+          .copied()
+          .filter(|&v| {
+            self.is_undirected_neighbor(vertex, v)
+          })
+          .collect()
+      };
+
+      clique.push(vertex);
+      self.bron_kerbosch(
+        clique,
+        neighbors(&candidates),
+        neighbors(&excluded),
+        largest,
+      );
+      clique.pop();
+
+      candidates.retain(|&v| v != vertex);
+      excluded.push(vertex);
+    }
+  }
+
+  /// Returns the vertices of a maximum clique of the graph,
+  /// that is, of a largest set of vertices with an edge
+  /// between each pair of them, in ascending order.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  ///
+  /// This takes exponential time in the worst case, so it
+  /// is only practical for small graphs, with up to around
+  /// 40 vertices.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5>::cycle();
+  /// assert_eq!(graph.max_clique().len(), 2);
+  ///
+  /// graph.add_edge(1, 3);
+  /// graph.add_edge(3, 0);
+  /// assert_eq!(graph.max_clique(), vec![0, 1, 3]);
+  /// ```
+  /// See also [Graph::max_independent_set].
+  #[cfg(feature = "alloc")]
+  pub fn max_clique(&self) -> Vec<usize> {
+    let mut largest = Vec::new();
+    self.bron_kerbosch(
+      &mut Vec::new(),
+      (0..SIZE).collect(),
+      Vec::new(),
+      &mut largest,
+    );
+
+    largest.sort_unstable();
+    largest
+  }
+
+  /// Returns the vertices of a maximum independent set of
+  /// the graph, that is, of a largest set of vertices
+  /// without edges between them, in ascending order.
+  /// This is a maximum clique of the complement of the
+  /// graph, so, just like [Graph::max_clique], it takes
+  /// exponential time and the graph is treated as
+  /// undirected.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<5>::cycle();
+  /// assert_eq!(graph.max_independent_set(), vec![0, 2]);
+  ///
+  /// let graph = Graph::<5>::star();
+  /// assert_eq!(
+  ///   graph.max_independent_set(),
+  ///   vec![1, 2, 3, 4]
+  /// );
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn max_independent_set(&self) -> Vec<usize> {
+    let mut undirected = *self;
+    undirected.symmetrize();
+    undirected.complement().max_clique()
+  }
+
+  /// Returns the local clustering coefficient of `vertex`,
+  /// that is, the fraction of pairs of its neighbors that
+  /// have an edge between them.
+  /// The graph is treated as undirected, self-loops are
+  /// ignored, and vertices with less than 2 neighbors have
+  /// a coefficient of 0.0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::star();
+  /// assert_eq!(graph.local_clustering(0), 0.0);
+  ///
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.local_clustering(0), 1.0 / 3.0);
+  /// assert_eq!(graph.local_clustering(1), 1.0);
+  /// assert_eq!(graph.local_clustering(3), 0.0);
+  /// ```
+  /// See also [Graph::global_clustering].
+  pub const fn local_clustering(
+    &self,
+    vertex: usize,
+  ) -> f32 {
+    let degree = self.undirected_degree(vertex);
+    if degree < 2 {
+      return 0.0;
+    }
+
+    let mut links = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      if i != vertex && self.has_edge_undirected(vertex, i)
+      {
+        let mut j = i + 1;
+        while j < SIZE {
+          if j != vertex
+            && self.has_edge_undirected(vertex, j)
+            && self.has_edge_undirected(i, j)
+          {
+            links += 1;
+          }
+
+          j += 1;
+        }
+      }
+
+      i += 1;
+    }
+
+    links as f32 / (degree * (degree - 1) / 2) as f32
+  }
+
+  /// Returns the global clustering coefficient of the
+  /// graph, also known as transitivity, that is, the
+  /// fraction of paths of 2 edges whose endpoints also
+  /// have an edge between them, closing a triangle.
+  /// The graph is treated as undirected, self-loops are
+  /// ignored, and graphs without such paths have a
+  /// coefficient of 0.0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::star();
+  /// assert_eq!(graph.global_clustering(), 0.0);
+  ///
+  /// graph.add_edge(1, 2);
+  /// assert_eq!(graph.global_clustering(), 0.6);
+  ///
+  /// let complete = Graph::<4>::complete();
+  /// assert_eq!(complete.global_clustering(), 1.0);
+  /// ```
+  /// See also [Graph::local_clustering] and
+  /// [Graph::count_triangles].
+  pub const fn global_clustering(&self) -> f32 {
+    let mut paths = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let degree = self.undirected_degree(vertex);
+      paths += degree * degree.saturating_sub(1) / 2;
+
+      vertex += 1;
+    }
+
+    if paths == 0 {
+      return 0.0;
+    }
+
+    (3 * self.count_triangles()) as f32 / paths as f32
+  }
+
+  /// Returns a new graph where each vertex `v` becomes
+  /// `permutation[v]`, so there is an edge between
+  /// `permutation[i]` and `permutation[j]` iff there is one
+  /// between `i` and `j` in the graph.
+  ///
+  /// Panics if `permutation` isn't a permutation of the
+  /// vertices, see [Graph::try_relabel].
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// let relabeled = graph.relabel(&[2, 0, 1]);
+  /// assert!(relabeled.has_edge(2, 0));
+  /// assert!(relabeled.has_edge(0, 1));
+  /// assert_eq!(relabeled.count_edges(), 2);
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::new();
+  /// graph.relabel(&[0, 1, 1]);
+  /// ```
+  pub const fn relabel(
+    &self,
+    permutation: &[usize; SIZE],
+  ) -> Graph<SIZE> {
+    match self.try_relabel(permutation) {
+      Ok(relabeled) => relabeled,
+      Err(_) => panic!("not a permutation of the vertices"),
+    }
+  }
+
+  /// Returns a new graph where each vertex `v` becomes
+  /// `permutation[v]`, just like [Graph::relabel], or an
+  /// error if `permutation` has a vertex that is out of
+  /// bounds or repeated.
+  /// ```
+  /// use const_graphs::{GraphError, Graph};
+  ///
+  /// let graph = Graph::<3>::new();
+  /// assert!(graph.try_relabel(&[1, 2, 0]).is_ok());
+  /// assert_eq!(
+  ///   graph.try_relabel(&[0, 2, 2]),
+  ///   Err(GraphError::RepeatedVertex { vertex: 2 })
+  /// );
+  /// assert_eq!(
+  ///   graph.try_relabel(&[0, 1, 3]),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 3,
+  ///     size: 3,
+  ///   })
+  /// );
+  /// ```
+  pub const fn try_relabel(
+    &self,
+    permutation: &[usize; SIZE],
+  ) -> Result<Graph<SIZE>, GraphError> {
+    if let Err(error) =
+      GraphError::check_permutation(permutation)
+    {
+      return Err(error);
+    }
+
+    let mut relabeled = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if self.data[i][j] {
+          relabeled
+            .add_edge(permutation[i], permutation[j]);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    Ok(relabeled)
+  }
+
+  /// Checks whether the graph is isomorphic to `other`,
+  /// that is, whether they are the same graph up to the
+  /// order of the vertices.
+  /// It runs in exponential time in the worst case, so it
+  /// is meant for small graphs, see
+  /// [Graph::isomorphism_mapping].
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(2, 0);
+  /// other.add_edge(0, 1);
+  /// assert!(graph.is_isomorphic(&other));
+  ///
+  /// other.add_self_loop(2);
+  /// assert!(!graph.is_isomorphic(&other));
+  /// ```
+  pub const fn is_isomorphic(
+    &self,
+    other: &Graph<SIZE>,
+  ) -> bool {
+    self.isomorphism_mapping(other).is_some()
+  }
+
+  /// Returns a permutation of the vertices that turns the
+  /// graph into `other` when passed to [Graph::relabel], or
+  /// `None` if the graph isn't isomorphic to `other`.
+  /// The vertices are mapped in order, backtracking as soon
+  /// as a vertex can't be mapped in a way consistent with
+  /// the previous ones or with a different in or out
+  /// degree, so if there are many ways to map them it takes
+  /// exponential time in the worst case.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(2, 0);
+  /// other.add_edge(0, 1);
+  /// let mapping = graph.isomorphism_mapping(&other);
+  /// assert_eq!(mapping, Some([2, 0, 1]));
+  /// assert!(graph.relabel(&mapping.unwrap()) == other);
+  ///
+  /// other.remove_edge(0, 1);
+  /// other.add_edge(1, 0);
+  /// assert_eq!(graph.isomorphism_mapping(&other), None);
+  /// ```
+  pub const fn isomorphism_mapping(
+    &self,
+    other: &Graph<SIZE>,
+  ) -> Option<[usize; SIZE]> {
+    if self.count_edges() != other.count_edges() {
+      return None;
+    }
+
+    let mut in_degrees = [[0; SIZE]; 2];
+    let mut out_degrees = [[0; SIZE]; 2];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      in_degrees[0][vertex] = self.in_degree(vertex);
+      in_degrees[1][vertex] = other.in_degree(vertex);
+      out_degrees[0][vertex] = self.out_degree(vertex);
+      out_degrees[1][vertex] = other.out_degree(vertex);
+
+      vertex += 1;
+    }
+
+    let mut mapping = [usize::MAX; SIZE];
+    let mut used = [false; SIZE];
+
+    // The vertices before `vertex` are mapped, and
+    // `mapping[vertex]` is the last candidate tried for it.
+    let mut vertex = 0;
+    while vertex < SIZE {
+      let mut candidate = if mapping[vertex] == usize::MAX {
+        0
+      } else {
+        used[mapping[vertex]] = false;
+        mapping[vertex] + 1
+      };
+
+      while candidate < SIZE {
+        if !used[candidate]
+          && in_degrees[0][vertex]
+            == in_degrees[1][candidate]
+          && out_degrees[0][vertex]
+            == out_degrees[1][candidate]
+          && self.data[vertex][vertex]
+            == other.data[candidate][candidate]
+        {
+          let mut consistent = true;
+
+          let mut previous = 0;
+          while previous < vertex && consistent {
+            let image = mapping[previous];
+            consistent = self.data[previous][vertex]
+              == other.data[image][candidate]
+              && self.data[vertex][previous]
+                == other.data[candidate][image];
+
+            previous += 1;
+          }
+
+          if consistent {
+            break;
+          }
+        }
+
+        candidate += 1;
+      }
+
+      if candidate < SIZE {
+        mapping[vertex] = candidate;
+        used[candidate] = true;
+        vertex += 1;
+      } else if vertex == 0 {
+        return None;
+      } else {
+        mapping[vertex] = usize::MAX;
+        vertex -= 1;
+      }
+    }
+
+    Some(mapping)
+  }
+
+  /// Swaps the vertices `u` and `v` in place, that is,
+  /// every edge from or to `u` now goes from or to `v`, and
+  /// the other way around, including the self-loops and
+  /// the edges between them.
+  /// It's the same as [Graph::relabel] with a permutation
+  /// that only swaps them, without creating a new graph.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_self_loop(0);
+  /// graph.add_edge(2, 0);
+  /// graph.swap_vertices(0, 1);
+  /// assert!(graph.has_edge(1, 0));
+  /// assert!(graph.has_self_loop(1));
+  /// assert!(graph.has_edge(2, 1));
+  /// assert_eq!(graph.count_edges(), 2);
+  /// ```
+  pub const fn swap_vertices(
+    &mut self,
+    u: usize,
+    v: usize,
+  ) {
+    // Swapping the rows and then the columns also swaps
+    // the diagonal entries.
+    let mut k = 0;
+    while k < SIZE {
+      let edge = self.data[u][k];
+      self.data[u][k] = self.data[v][k];
+      self.data[v][k] = edge;
+
+      k += 1;
+    }
+
+    let mut k = 0;
+    while k < SIZE {
+      let edge = self.data[k][u];
+      self.data[k][u] = self.data[k][v];
+      self.data[k][v] = edge;
+
+      k += 1;
+    }
+  }
+
+  /// Returns a new graph where `v` is merged into `u`, that
+  /// is, every edge from or to `v` now goes from or to `u`
+  /// instead, and the edges between them, which would
+  /// become self-loops, are dropped.
+  /// The number of vertices of the graph is fixed, so `v`
+  /// isn't removed, it just becomes isolated, and
+  /// contracting a vertex into itself changes nothing.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(3, 1);
+  /// let contracted = graph.contract(0, 1);
+  /// assert!(contracted.has_edge(0, 2));
+  /// assert!(contracted.has_edge(3, 0));
+  /// assert!(!contracted.has_edge(0, 0));
+  /// assert!(contracted.is_isolated(1));
+  /// ```
+  pub const fn contract(
+    &self,
+    u: usize,
+    v: usize,
+  ) -> Graph<SIZE> {
+    let mut contracted = *self;
+    if u == v {
+      return contracted;
+    }
+
+    let mut w = 0;
+    while w < SIZE {
+      if w != u && w != v {
+        if self.data[v][w] {
+          contracted.add_edge(u, w);
+        }
+        if self.data[w][v] {
+          contracted.add_edge(w, u);
+        }
+      }
+
+      w += 1;
+    }
+
+    contracted.remove_vertex(v);
+    contracted
+  }
+
+  /// Returns the degree matrix of the graph, that is, a
+  /// matrix with the number of neighbors of each vertex in
+  /// the diagonal and zeros everywhere else.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::path();
+  /// assert_eq!(
+  ///   graph.degree_matrix(),
+  ///   [[1, 0, 0], [0, 2, 0], [0, 0, 1]]
+  /// );
+  /// ```
+  /// See also [Graph::laplacian].
+  pub const fn degree_matrix(&self) -> [[i64; SIZE]; SIZE] {
+    let mut matrix = [[0; SIZE]; SIZE];
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      matrix[vertex][vertex] =
+        self.undirected_degree(vertex) as i64;
+
+      vertex += 1;
+    }
+
+    matrix
+  }
+
+  /// Returns the Laplacian matrix of the graph, that is,
+  /// the degree matrix minus the adjacency matrix, so the
+  /// diagonal has the number of neighbors of each vertex,
+  /// the element `[i][j]` is -1 if there is an edge between
+  /// `i` and `j`, and every row adds up to 0.
+  /// The graph is treated as undirected, and self-loops are
+  /// ignored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::path();
+  /// assert_eq!(
+  ///   graph.laplacian(),
+  ///   [[1, -1, 0], [-1, 2, -1], [0, -1, 1]]
+  /// );
+  /// ```
+  /// See also [Graph::degree_matrix].
+  pub const fn laplacian(&self) -> [[i64; SIZE]; SIZE] {
+    let mut matrix = self.degree_matrix();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if self.is_undirected_neighbor(i, j) {
+          matrix[i][j] = -1;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    matrix
+  }
+
+  /// Returns a new graph with every edge reversed, that is,
+  /// there is an edge between `j` and `i` in the result iff
+  /// there is an edge between `i` and `j` in the graph.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// let transposed = graph.transpose();
+  /// assert!(transposed.has_edge(2, 0));
+  /// assert!(!transposed.has_edge(0, 2));
+  /// ```
+  /// See also [Graph::get_inverse_edges].
+  pub const fn transpose(&self) -> Graph<SIZE> {
+    let mut transposed = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        transposed.set_edge_state(j, i, self.data[i][j]);
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    transposed
+  }
+
+  /// Reverses every edge of the graph in place, just like
+  /// [Graph::transpose] but without creating a new graph.
+  /// Doing it twice gives back the original graph.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.transpose_mut();
+  /// assert!(graph.has_edge(2, 0));
+  /// assert!(!graph.has_edge(0, 2));
+  ///
+  /// graph.transpose_mut();
+  /// assert!(graph.has_edge(0, 2));
+  /// ```
+  pub const fn transpose_mut(&mut self) {
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        let edge = self.data[i][j];
+        self.data[i][j] = self.data[j][i];
+        self.data[j][i] = edge;
+
+        j += 1;
+      }
+      i += 1;
+    }
+  }
+
+  /// Makes the graph undirected in place, adding an edge
+  /// between `j` and `i` for every edge between `i` and
+  /// `j`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.symmetrize();
+  /// assert!(graph.has_edge(2, 0));
+  /// assert!(graph.is_symmetric());
+  /// assert_eq!(graph.count_edges(), 4);
+  /// ```
+  /// See also [Graph::is_symmetric].
+  pub const fn symmetrize(&mut self) {
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        let edge = self.data[i][j] || self.data[j][i];
+        self.set_edge_state(i, j, edge);
+        self.set_edge_state(j, i, edge);
+
+        j += 1;
+      }
+      i += 1;
+    }
+  }
+
+  /// Returns the complement of the graph, that is, a new
+  /// graph with an edge between `i` and `j` iff there is no
+  /// such edge in the graph.
+  /// The diagonal is always cleared, so the result never
+  /// has self-loops, even if the graph has none.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// let complement = graph.complement();
+  /// assert!(!complement.has_edge(0, 1));
+  /// assert!(complement.has_edge(1, 0));
+  /// assert!(!complement.has_edge(0, 0));
+  /// ```
+  pub const fn complement(&self) -> Graph<SIZE> {
+    let mut complement = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        complement.set_edge_state(
+          i,
+          j,
+          i != j && !self.data[i][j],
+        );
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    complement
+  }
+
+  /// Returns a new graph with an edge between `i` and `j`
+  /// iff there is such edge in the graph or in `other`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(0, 2);
+  /// other.add_edge(1, 2);
+  /// let union = graph.union(&other);
+  /// assert!(union.has_edge(0, 1));
+  /// assert!(union.has_edge(0, 2));
+  /// assert!(union.has_edge(1, 2));
+  /// ```
+  /// See also [Graph::intersection] and
+  /// [Graph::symmetric_difference].
+  pub const fn union(
+    &self,
+    other: &Graph<SIZE>,
+  ) -> Graph<SIZE> {
+    let mut result = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        result.set_edge_state(
+          i,
+          j,
+          self.data[i][j] || other.data[i][j],
+        );
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    result
+  }
+
+  /// Returns a new graph with an edge between `i` and `j`
+  /// iff there is such edge both in the graph and in
+  /// `other`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(0, 2);
+  /// other.add_edge(1, 2);
+  /// let intersection = graph.intersection(&other);
+  /// assert!(!intersection.has_edge(0, 1));
+  /// assert!(intersection.has_edge(0, 2));
+  /// assert!(!intersection.has_edge(1, 2));
+  /// ```
+  /// See also [Graph::union] and
+  /// [Graph::symmetric_difference].
+  pub const fn intersection(
+    &self,
+    other: &Graph<SIZE>,
+  ) -> Graph<SIZE> {
+    let mut result = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        result.set_edge_state(
+          i,
+          j,
+          self.data[i][j] && other.data[i][j],
+        );
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    result
+  }
+
+  /// Returns a new graph with an edge between `i` and `j`
+  /// iff there is such edge either in the graph or in
+  /// `other`, but not in both.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(0, 2);
+  /// let mut other = Graph::<3>::new();
+  /// other.add_edge(0, 2);
+  /// other.add_edge(1, 2);
+  /// let difference = graph.symmetric_difference(&other);
+  /// assert!(difference.has_edge(0, 1));
+  /// assert!(!difference.has_edge(0, 2));
+  /// assert!(difference.has_edge(1, 2));
+  /// ```
+  /// See also [Graph::union] and [Graph::intersection].
+  pub const fn symmetric_difference(
+    &self,
+    other: &Graph<SIZE>,
+  ) -> Graph<SIZE> {
+    let mut result = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        result.set_edge_state(
+          i,
+          j,
+          self.data[i][j] != other.data[i][j],
+        );
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    result
+  }
+
+  /// Returns the adjacency list of the graph, where the
+  /// `v`th list has the neighbors of `v` in ascending
+  /// order.
+  /// Unlike most methods, it allocates, so it requires the
+  /// `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(
+  ///   graph.to_adjacency_list(),
+  ///   [vec![1, 2], vec![], vec![0]]
+  /// );
+  /// ```
+  /// See also [Graph::neighbors].
+  #[cfg(feature = "alloc")]
+  pub fn to_adjacency_list(&self) -> Vec<Vec<usize>> {
+    (0..SIZE)
+      .map(|vertex| self.neighbors(vertex).collect())
+      .collect()
+  }
+
+  /// Returns the graph in the compressed sparse row (CSR)
+  /// format, as the row pointers and the column indices.
+  /// The neighbors of `v` are in ascending order in
+  /// `columns[row_pointers[v]..row_pointers[v + 1]]`, so
+  /// `row_pointers` has `SIZE + 1` elements, starting at
+  /// `0` and ending at the number of edges, self-loops
+  /// included.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 2);
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 2);
+  /// let (row_pointers, columns) = graph.to_csr();
+  /// assert_eq!(row_pointers, [0, 2, 2, 3]);
+  /// assert_eq!(columns, [1, 2, 2]);
+  /// ```
+  /// See also [Graph::to_adjacency_list].
+  #[cfg(feature = "alloc")]
+  pub fn to_csr(&self) -> (Vec<usize>, Vec<usize>) {
+    let mut row_pointers = Vec::with_capacity(SIZE + 1);
+    let mut columns = Vec::new();
+
+    row_pointers.push(0);
+    for vertex in 0..SIZE {
+      columns.extend(self.neighbors(vertex));
+      row_pointers.push(columns.len());
+    }
+
+    (row_pointers, columns)
+  }
+
+  /// Returns the incidence matrix of the graph, with one
+  /// row per edge, where the entry of the vertex the edge
+  /// goes from is `-1`, the one of the vertex it goes to is
+  /// `1` and the others are `0`.
+  /// The edges are in row order of the adjacency matrix,
+  /// that is, sorted by the vertex they go from and then by
+  /// the one they go to, and self-loops are ignored, so
+  /// there are [Graph::count_edges] rows.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(2, 0);
+  /// graph.add_edge(0, 1);
+  /// graph.add_self_loop(1);
+  /// assert_eq!(
+  ///   graph.incidence_matrix(),
+  ///   [[-1, 1, 0], [1, 0, -1]]
+  /// );
+  /// ```
+  /// See also [Graph::incidence_matrix_undirected].
+  #[cfg(feature = "alloc")]
+  pub fn incidence_matrix(&self) -> Vec<[i8; SIZE]> {
+    let mut matrix = Vec::with_capacity(self.count_edges());
+
+    for i in 0..SIZE {
+      for j in self.neighbors(i) {
+        if i != j {
+          let mut row = [0; SIZE];
+          row[i] = -1;
+          row[j] = 1;
+          matrix.push(row);
+        }
+      }
+    }
+
+    matrix
+  }
+
+  /// Returns the incidence matrix of the graph as an
+  /// undirected graph, with one row per pair of vertices
+  /// with an edge between them in any direction, where the
+  /// entries of both vertices are `1` and the others are
+  /// `0`.
+  /// The pairs are sorted by their smallest vertex and then
+  /// by the largest one, and self-loops are ignored.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(1, 2);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(
+  ///   graph.incidence_matrix_undirected(),
+  ///   [[1, 0, 1], [0, 1, 1]]
+  /// );
+  /// ```
+  /// See also [Graph::incidence_matrix].
+  #[cfg(feature = "alloc")]
+  pub fn incidence_matrix_undirected(
+    &self,
+  ) -> Vec<[i8; SIZE]> {
+    let mut matrix = Vec::new();
+
+    for i in 0..SIZE {
+      for j in i + 1..SIZE {
+        if self.has_edge_undirected(i, j) {
+          let mut row = [0; SIZE];
+          row[i] = 1;
+          row[j] = 1;
+          matrix.push(row);
+        }
+      }
+    }
+
+    matrix
+  }
+
+  /// Returns the graph in the Graphviz DOT format, with
+  /// one line per edge.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(
+  ///   graph.to_dot(),
+  ///   "digraph {\n  0 -> 1;\n  2 -> 0;\n}\n"
+  /// );
+  /// ```
+  /// See also [Graph::to_dot_undirected].
+  #[cfg(feature = "alloc")]
+  pub fn to_dot(&self) -> String {
+    let mut dot = String::from("digraph {\n");
+
+    for i in 0..SIZE {
+      for j in self.neighbors(i) {
+        // Writing to a String never fails.
+        let _ = writeln!(dot, "  {i} -> {j};");
+      }
+    }
+
+    dot.push_str("}\n");
+    dot
+  }
+
+  /// Returns the graph in the Graphviz DOT format as an
+  /// undirected graph, with one line per pair of vertices
+  /// with an edge between them in any direction.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge_undirected(0, 1);
+  /// graph.add_edge(2, 0);
+  /// assert_eq!(
+  ///   graph.to_dot_undirected(),
+  ///   "graph {\n  0 -- 1;\n  0 -- 2;\n}\n"
+  /// );
+  /// ```
+  /// See also [Graph::to_dot].
+  #[cfg(feature = "alloc")]
+  pub fn to_dot_undirected(&self) -> String {
+    let mut dot = String::from("graph {\n");
+
+    for i in 0..SIZE {
+      for j in i..SIZE {
+        if self.data[i][j] || self.data[j][i] {
+          // Writing to a String never fails.
+          let _ = writeln!(dot, "  {i} -- {j};");
+        }
+      }
+    }
+
+    dot.push_str("}\n");
+    dot
+  }
+
+  /// Parses a graph in the Graphviz DOT format, with the
+  /// vertices as their indices.
+  /// Only a `digraph` with `->` edges or a `graph` with
+  /// `--` edges are supported, the edges of a `graph` are
+  /// added in both directions.
+  /// The statements are separated by `;` or new lines, the
+  /// edges can be chained, like `0 -> 1 -> 2`, and the
+  /// attributes in brackets are ignored, even with `;` in
+  /// their quoted values, as well as the `node`, `edge` and
+  /// `graph` attribute statements.
+  /// Requires the `interop` feature.
+  /// ```
+  /// use const_graphs::{Graph, ParseError};
+  ///
+  /// let dot = "digraph {
+  ///   0 -> 1 -> 2;
+  ///   2 -> 0 [label=\"a\"]
+  /// }";
+  /// let graph = Graph::<3>::from_dot(dot).unwrap();
+  /// assert!(graph.has_edge(0, 1));
+  /// assert!(graph.has_edge(1, 2));
+  /// assert!(graph.has_edge(2, 0));
+  /// assert_eq!(graph.count_edges(), 3);
+  ///
+  /// let undirected =
+  ///   Graph::<3>::from_dot("graph { 0 -- 1 }").unwrap();
+  /// assert!(undirected.has_edge(1, 0));
+  ///
+  /// let dot =
+  ///   r#"digraph { 0 -> 1 [label="a;b"]; 1 -> 2 }"#;
+  /// let graph = Graph::<3>::from_dot(dot).unwrap();
+  /// assert!(graph.has_edge(0, 1));
+  /// assert!(graph.has_edge(1, 2));
+  ///
+  /// assert_eq!(
+  ///   Graph::<3>::from_dot(&graph.to_dot()),
+  ///   Ok(graph)
+  /// );
+  ///
+  /// assert_eq!(
+  ///   Graph::<3>::from_dot("digraph {\n  0 -> 3;\n}"),
+  ///   Err(ParseError::VertexOutOfBounds {
+  ///     line: 2,
+  ///     vertex: 3,
+  ///     size: 3,
+  ///   })
+  /// );
+  /// let dot = r#"digraph { 0 -> 1 [label="a }"#;
+  /// assert_eq!(
+  ///   Graph::<3>::from_dot(dot),
+  ///   Err(ParseError::InvalidStatement { line: 1 })
+  /// );
+  /// ```
+  /// See also [Graph::to_dot] and
+  /// [Graph::to_dot_undirected].
+  #[cfg(feature = "interop")]
+  pub fn from_dot(
+    input: &str,
+  ) -> Result<Graph<SIZE>, ParseError> {
+    let start = match input.find('{') {
+      Some(start) => start,
+      None => return Err(ParseError::InvalidHeader),
+    };
+
+    let mut header = input[..start].split_whitespace();
+    let directed = match header.next() {
+      Some("digraph") => true,
+      Some("graph") => false,
+      _ => return Err(ParseError::InvalidHeader),
+    };
+    // The name of the graph is optional.
+    header.next();
+    if header.next().is_some() {
+      return Err(ParseError::InvalidHeader);
+    }
+
+    let body = input[start + 1..].trim_end();
+    let body = match body.strip_suffix('}') {
+      Some(body) => body,
+      None => return Err(ParseError::MissingClosingBrace),
+    };
+
+    let first_line =
+      input[..start].matches('\n').count() + 1;
+
+    let mut graph = Graph::new();
+
+    for (index, text) in body.split('\n').enumerate() {
+      let line = first_line + index;
+
+      // The statements end at the `;` outside of the
+      // attributes, which can have quoted strings with
+      // escaped quotes, and at the end of the line.
+      let mut statement = 0;
+      let mut open = None;
+      let mut close = None;
+      let mut quoted = false;
+      let mut escaped = false;
+
+      let end = core::iter::once((text.len(), ';'));
+      for (position, character) in
+        text.char_indices().chain(end)
+      {
+        if quoted {
+          if escaped {
+            escaped = false;
+          } else if character == '\\' {
+            escaped = true;
+          } else if character == '"' {
+            quoted = false;
+          }
+          continue;
+        }
+
+        match (character, open, close) {
+          ('"', _, _) => quoted = true,
+          ('[', None, _) => open = Some(position),
+          (']', Some(_), None) => {
+            close = Some(position + 1)
+          }
+          (';', None, _) => {
+            graph.add_dot_statement(
+              &text[statement..position],
+              "",
+              directed,
+              line,
+            )?;
+            statement = position + 1;
+          }
+          (';', Some(attributes), Some(rest)) => {
+            graph.add_dot_statement(
+              &text[statement..attributes],
+              &text[rest..position],
+              directed,
+              line,
+            )?;
+            statement = position + 1;
+            (open, close) = (None, None);
+          }
+          _ => {}
+        }
+      }
+
+      if quoted || open.is_some() {
+        return Err(ParseError::InvalidStatement { line });
+      }
+    }
+
+    Ok(graph)
+  }
+
+  /// Adds the edges of a statement of [Graph::from_dot],
+  /// where `statement` is the part before its attributes
+  /// and `rest` the part after them.
+  #[cfg(feature = "interop")]
+  fn add_dot_statement(
+    &mut self,
+    statement: &str,
+    rest: &str,
+    directed: bool,
+    line: usize,
+  ) -> Result<(), ParseError> {
+    let (operator, other_operator) =
+      if directed { ("->", "--") } else { ("--", "->") };
+
+    let statement = statement.trim();
+    if !rest.trim().is_empty()
+      || statement.contains(other_operator)
+    {
+      return Err(ParseError::InvalidStatement { line });
+    }
+    if matches!(statement, "" | "node" | "edge" | "graph") {
+      return Ok(());
+    }
+
+    let mut previous = None;
+    for vertex in statement.split(operator) {
+      let vertex = match vertex.trim().parse::<usize>() {
+        Ok(vertex) => vertex,
+        Err(_) => {
+          return Err(ParseError::InvalidVertex { line })
+        }
+      };
+      if vertex >= SIZE {
+        return Err(ParseError::VertexOutOfBounds {
+          line,
+          vertex,
+          size: SIZE,
+        });
+      }
+
+      if let Some(previous) = previous {
+        if directed {
+          self.add_edge(previous, vertex);
+        } else {
+          self.add_edge_undirected(previous, vertex);
+        }
+      }
+      previous = Some(vertex);
+    }
+
+    Ok(())
+  }
+
+  /// Returns a new graph with only the edges whose both
+  /// endpoints are in `vertices`, ignoring the vertices
+  /// that are out of bounds.
+  /// The vertices keep their indices, and the ones that are
+  /// not in `vertices` are left isolated.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// graph.add_edge(2, 0);
+  /// let subgraph = graph.subgraph(&[0, 1, 7]);
+  /// assert!(subgraph.has_edge(0, 1));
+  /// assert!(!subgraph.has_edge(1, 2));
+  /// assert!(!subgraph.has_edge(2, 0));
+  /// ```
+  /// See also [Graph::induced_subgraph].
+  pub const fn subgraph(
+    &self,
+    vertices: &[usize],
+  ) -> Graph<SIZE> {
+    let mut selected = [false; SIZE];
+
+    let mut k = 0;
+    while k < vertices.len() {
+      if vertices[k] < SIZE {
+        selected[vertices[k]] = true;
+      }
+
+      k += 1;
+    }
+
+    let mut subgraph = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        subgraph.set_edge_state(
+          i,
+          j,
+          selected[i] && selected[j] && self.data[i][j],
+        );
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    subgraph
+  }
+
+  /// Returns the subgraph induced by `vertices`, just like
+  /// [Graph::subgraph], but panics if any of them is out of
+  /// bounds.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<4>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(1, 2);
+  /// let subgraph = graph.induced_subgraph(&[1, 2]);
+  /// assert!(!subgraph.has_edge(0, 1));
+  /// assert!(subgraph.has_edge(1, 2));
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<4>::new();
+  /// graph.induced_subgraph(&[1, 4]);
+  /// ```
+  pub const fn induced_subgraph(
+    &self,
+    vertices: &[usize],
+  ) -> Graph<SIZE> {
+    let mut k = 0;
+    while k < vertices.len() {
+      assert!(vertices[k] < SIZE, "vertex out of bounds");
+
+      k += 1;
+    }
+
+    self.subgraph(vertices)
+  }
+
+  /// Returns a new graph with only the edges between `i`
+  /// and `j` for which `keep(i, j)` is true.
+  /// `keep` is only called for the edges present in the
+  /// graph.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// graph.add_edge(2, 1);
+  /// let filtered = graph.filter_edges(|i, j| i < j);
+  /// assert!(filtered.has_edge(0, 1));
+  /// assert!(!filtered.has_edge(2, 1));
+  /// ```
+  pub fn filter_edges<F: FnMut(usize, usize) -> bool>(
+    &self,
+    mut keep: F,
+  ) -> Graph<SIZE> {
+    let mut filtered = Graph::new();
+
+    for (i, j) in self.edges() {
+      if keep(i, j) {
+        filtered.add_edge(i, j);
+      }
+    }
+
+    filtered
+  }
+
+  /// Returns a new weighted graph with the same edges as
+  /// the graph, all of them with `weight`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<3>::new();
+  /// graph.add_edge(0, 1);
+  /// let weighted = graph.to_weighted(0.5);
+  /// assert_eq!(weighted.get_edge(0, 1), Some(0.5));
+  /// assert!(!weighted.has_edge(1, 0));
+  /// ```
+  /// See also [WeightedGraph::to_unweighted].
+  pub const fn to_weighted<W: Copy>(
+    &self,
+    weight: W,
+  ) -> WeightedGraph<SIZE, W> {
+    let mut data = [[None; SIZE]; SIZE];
+
     let mut i = 0;
     while i < SIZE {
       let mut j = 0;
       while j < SIZE {
         if self.data[i][j] {
-          edges += 1;
+          data[i][j] = Some(weight);
         }
 
         j += 1;
@@ -198,7 +5052,7 @@ impl<const SIZE: usize> Graph<SIZE> {
       i += 1;
     }
 
-    edges as f32 / self.max_number_of_edges() as f32
+    WeightedGraph::from_matrix(data)
   }
 
   /// Remove all edges from the graph.
@@ -224,6 +5078,8 @@ impl<const SIZE: usize> Graph<SIZE> {
       }
       i += 1;
     }
+    self.edge_count = 0;
+    self.edge_count_stale = false;
   }
 
   /// Creates a new graph.
@@ -236,6 +5092,669 @@ impl<const SIZE: usize> Graph<SIZE> {
   pub const fn new() -> Graph<SIZE> {
     Graph {
       data: [[false; SIZE]; SIZE],
+      edge_count: 0,
+      edge_count_stale: false,
+    }
+  }
+
+  /// Creates a new graph on the heap, without creating it
+  /// on the stack first, so it works even for graphs too
+  /// large for the stack.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let mut graph = Graph::<5_000>::new_boxed();
+  /// graph.add_edge(0, 4_999);
+  /// assert!(graph.has_edge(0, 4_999));
+  /// assert_eq!(graph.count_edges(), 1);
+  /// ```
+  /// See also [Graph::new].
+  #[cfg(feature = "alloc")]
+  pub fn new_boxed() -> Box<Graph<SIZE>> {
+    // SAFETY: a graph is only booleans and a count, so all
+    // zeros is a valid graph, and it is the one without
+    // edges and with an up to date count.
+    unsafe { Box::new_zeroed().assume_init() }
+  }
+
+  /// Creates a new graph from an adjacency matrix, where
+  /// `data[i][j]` is whether there is an edge between `i`
+  /// and `j`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const GRAPH: Graph<3> = Graph::from_matrix([
+  ///   [false, true, false],
+  ///   [false, false, true],
+  ///   [false, false, false],
+  /// ]);
+  /// assert!(GRAPH.has_edge(0, 1));
+  /// assert!(GRAPH.has_edge(1, 2));
+  /// assert!(!GRAPH.has_edge(2, 0));
+  /// ```
+  pub const fn from_matrix(
+    data: [[bool; SIZE]; SIZE],
+  ) -> Graph<SIZE> {
+    Graph {
+      edge_count: Graph::count_matrix_edges(&data),
+      edge_count_stale: false,
+      data,
+    }
+  }
+
+  /// Creates a new graph with an edge between `i` and `j`
+  /// iff `f(i, j)` returns `true`, calling it once for
+  /// each pair of vertices, in row order.
+  /// Unlike [Graph::from_matrix], it can't be used in const
+  /// contexts, since it takes a closure.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<4>::from_fn(|i, j| j == 2 * i);
+  /// assert!(graph.has_edge(0, 0));
+  /// assert!(graph.has_edge(1, 2));
+  /// assert!(!graph.has_edge(2, 1));
+  /// assert_eq!(graph.count_edges(), 1);
+  /// ```
+  pub fn from_fn<F: FnMut(usize, usize) -> bool>(
+    mut f: F,
+  ) -> Graph<SIZE> {
+    let mut graph = Graph::new();
+
+    for i in 0..SIZE {
+      for j in 0..SIZE {
+        graph.set_edge_state(i, j, f(i, j));
+      }
+    }
+
+    graph
+  }
+
+  /// Creates a new graph with an edge between `i` and `j`
+  /// for each `(i, j)` in `edges`.
+  ///
+  /// Panics if any vertex is out of bounds.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::from_edges(&[(0, 1), (1, 2)]);
+  /// assert!(graph.has_edge(0, 1));
+  /// assert!(graph.has_edge(1, 2));
+  /// assert!(!graph.has_edge(1, 0));
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::from_edges(&[(0, 3)]);
+  /// ```
+  /// See also [Graph::from_edges_undirected].
+  pub fn from_edges(
+    edges: &[(usize, usize)],
+  ) -> Graph<SIZE> {
+    let mut graph = Graph::new();
+    graph.add_edges(edges);
+    graph
+  }
+
+  /// Creates a new graph with an undirected edge between
+  /// `i` and `j` for each `(i, j)` in `edges`.
+  ///
+  /// Panics if any vertex is out of bounds.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<3>::from_edges_undirected(&[
+  ///   (0, 1),
+  ///   (1, 2),
+  /// ]);
+  /// assert!(graph.has_edge(0, 1));
+  /// assert!(graph.has_edge(1, 0));
+  /// assert!(!graph.has_edge(0, 2));
+  /// ```
+  /// See also [Graph::from_edges].
+  pub fn from_edges_undirected(
+    edges: &[(usize, usize)],
+  ) -> Graph<SIZE> {
+    let mut graph = Graph::new();
+    graph.add_edges_undirected(edges);
+    graph
+  }
+
+  /// Creates a new random graph where each edge between
+  /// two different vertices is present with `probability`,
+  /// that is, an Erdős–Rényi graph.
+  ///
+  /// The graph only depends on `seed`: the edges are
+  /// visited in row-major order, and each one is present if
+  /// the next number of a xorshift64 generator (with the
+  /// shifts 13, 7 and 17) shifted right by 11 and divided
+  /// by 2^53 is smaller than `probability`.
+  /// The generator starts with `seed`, or with
+  /// `0x9E3779B97F4A7C15` if it is 0.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const GRAPH: Graph<10> = Graph::random(0.5, 42);
+  /// assert!(GRAPH == Graph::random(0.5, 42));
+  /// assert!(GRAPH.count_edges() > 0);
+  ///
+  /// let empty = Graph::<10>::random(0.0, 42);
+  /// assert_eq!(empty.count_edges(), 0);
+  ///
+  /// // The same in every platform.
+  /// assert!(Graph::<4>::random(0.5, 1).edges().eq([
+  ///   (0, 1),
+  ///   (0, 2),
+  ///   (2, 3),
+  ///   (3, 0),
+  ///   (3, 1),
+  /// ]));
+  /// ```
+  /// See also [Graph::random_undirected].
+  pub const fn random(
+    probability: f64,
+    seed: u64,
+  ) -> Graph<SIZE> {
+    let mut generator = XorShift64::new(seed);
+    let mut graph = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if i != j && generator.next_f64() < probability {
+          graph.add_edge(i, j);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    graph
+  }
+
+  /// Creates a new random undirected graph where each edge
+  /// between two different vertices is present with
+  /// `probability`.
+  ///
+  /// Just like [Graph::random], the graph only depends on
+  /// `seed`, but only the edges between `i` and `j` with
+  /// `i < j` are generated, and then mirrored.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// let graph = Graph::<10>::random_undirected(0.5, 42);
+  /// assert!(graph.is_symmetric());
+  /// assert!(graph == Graph::random_undirected(0.5, 42));
+  /// ```
+  pub const fn random_undirected(
+    probability: f64,
+    seed: u64,
+  ) -> Graph<SIZE> {
+    let mut generator = XorShift64::new(seed);
+    let mut graph = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        if generator.next_f64() < probability {
+          graph.add_edge_undirected(i, j);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    graph
+  }
+
+  /// Creates a new random tournament, that is, a graph
+  /// with exactly one of the edges between `i` and `j` and
+  /// between `j` and `i` for every pair of different
+  /// vertices, and no self-loops.
+  ///
+  /// Just like [Graph::random_undirected], the graph only
+  /// depends on `seed`, and the pairs with `i < j` are
+  /// visited in row-major order, but the edge goes from `i`
+  /// to `j` if the generated number is smaller than 0.5,
+  /// and from `j` to `i` otherwise.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const GRAPH: Graph<5> = Graph::random_tournament(42);
+  /// assert!(GRAPH == Graph::random_tournament(42));
+  /// assert_eq!(GRAPH.count_edges(), 10);
+  ///
+  /// let mut graph = GRAPH;
+  /// graph.symmetrize();
+  /// assert!(graph.is_complete());
+  /// ```
+  pub const fn random_tournament(seed: u64) -> Graph<SIZE> {
+    let mut generator = XorShift64::new(seed);
+    let mut graph = Graph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        if generator.next_f64() < 0.5 {
+          graph.add_edge(i, j);
+        } else {
+          graph.add_edge(j, i);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    graph
+  }
+
+  /// Creates a new complete graph, that is, a graph with an
+  /// edge between every pair of different vertices, and no
+  /// self-loops.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const K5: Graph<5> = Graph::complete();
+  /// assert!(K5.has_edge(0, 4));
+  /// assert!(!K5.has_edge(2, 2));
+  /// assert_eq!(K5.density(), 1.0);
+  /// ```
+  pub const fn complete() -> Graph<SIZE> {
+    Graph::new().complement()
+  }
+
+  /// Creates a new path graph, that is, a graph with an
+  /// undirected edge between each vertex `i` and `i + 1`.
+  /// Graphs with less than 2 vertices have no edges.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const PATH: Graph<4> = Graph::path();
+  /// assert!(PATH.has_edge(0, 1));
+  /// assert!(PATH.has_edge(3, 2));
+  /// assert!(!PATH.has_edge(3, 0));
+  /// ```
+  /// See also [Graph::cycle].
+  pub const fn path() -> Graph<SIZE> {
+    let mut graph = Graph::new();
+
+    let mut i = 1;
+    while i < SIZE {
+      graph.add_edge_undirected(i - 1, i);
+
+      i += 1;
     }
+
+    graph
+  }
+
+  /// Creates a new cycle graph, that is, a
+  /// [path graph](Graph::path) with an undirected edge
+  /// between the last and the first vertices.
+  /// Graphs with less than 3 vertices can't have a cycle,
+  /// so they are just paths.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const CYCLE: Graph<4> = Graph::cycle();
+  /// assert!(CYCLE.has_edge(0, 1));
+  /// assert!(CYCLE.has_edge(3, 0));
+  /// assert!(!CYCLE.has_edge(0, 2));
+  ///
+  /// assert!(Graph::<2>::cycle() == Graph::path());
+  /// ```
+  pub const fn cycle() -> Graph<SIZE> {
+    let mut graph = Graph::path();
+
+    if SIZE >= 3 {
+      graph.add_edge_undirected(SIZE - 1, 0);
+    }
+
+    graph
+  }
+
+  /// Creates a new star graph, that is, a graph with an
+  /// undirected edge between vertex 0, the center, and
+  /// every other vertex.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const STAR: Graph<4> = Graph::star();
+  /// assert!(STAR.has_edge(0, 3));
+  /// assert!(STAR.has_edge(2, 0));
+  /// assert!(!STAR.has_edge(1, 2));
+  /// ```
+  /// See also [Graph::star_at].
+  pub const fn star() -> Graph<SIZE> {
+    Graph::star_at(0)
+  }
+
+  /// Creates a new star graph with `center` as its center,
+  /// that is, a graph with an undirected edge between
+  /// `center` and every other vertex.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// const STAR: Graph<4> = Graph::star_at(2);
+  /// assert!(STAR.has_edge(2, 0));
+  /// assert!(STAR.has_edge(3, 2));
+  /// assert!(!STAR.has_edge(0, 1));
+  /// assert!(!STAR.has_edge(2, 2));
+  /// ```
+  pub const fn star_at(center: usize) -> Graph<SIZE> {
+    let mut graph = Graph::new();
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if vertex != center {
+        graph.add_edge_undirected(center, vertex);
+      }
+
+      vertex += 1;
+    }
+
+    graph
+  }
+
+  /// Creates a new grid graph with `rows` rows and `cols`
+  /// columns, with the cell in `row` and `col` numbered as
+  /// `row * cols + col`, connected to the cells around it,
+  /// including the diagonal ones if `diagonals`.
+  const fn grid_with(
+    rows: usize,
+    cols: usize,
+    diagonals: bool,
+  ) -> Graph<SIZE> {
+    assert!(
+      rows * cols <= SIZE,
+      "the grid has more cells than the graph has vertices"
+    );
+
+    let mut graph = Graph::new();
+
+    let mut row = 0;
+    while row < rows {
+      let mut col = 0;
+      while col < cols {
+        let cell = row * cols + col;
+
+        if col + 1 < cols {
+          graph.add_edge_undirected(cell, cell + 1);
+        }
+
+        if row + 1 < rows {
+          graph.add_edge_undirected(cell, cell + cols);
+
+          if diagonals && col + 1 < cols {
+            graph
+              .add_edge_undirected(cell, cell + cols + 1);
+          }
+
+          if diagonals && col > 0 {
+            graph
+              .add_edge_undirected(cell, cell + cols - 1);
+          }
+        }
+
+        col += 1;
+      }
+      row += 1;
+    }
+
+    graph
+  }
+
+  /// Creates a new grid graph with `rows` rows and `cols`
+  /// columns, where each cell has an undirected edge to the
+  /// cells above, below, on the left and on the right of
+  /// it.
+  /// The cell in `row` and `col` is the vertex
+  /// `row * cols + col`, and the vertices after the last
+  /// cell are left isolated.
+  ///
+  /// Panics if `rows * cols` is greater than `SIZE`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// // 0 1 2
+  /// // 3 4 5
+  /// const GRID: Graph<6> = Graph::grid(2, 3);
+  /// assert!(GRID.has_edge(0, 1));
+  /// assert!(GRID.has_edge(1, 4));
+  /// assert!(!GRID.has_edge(2, 3));
+  /// assert!(!GRID.has_edge(0, 4));
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::Graph;
+  ///
+  /// let grid = Graph::<6>::grid(3, 3);
+  /// ```
+  /// See also [Graph::grid_8].
+  pub const fn grid(
+    rows: usize,
+    cols: usize,
+  ) -> Graph<SIZE> {
+    Graph::grid_with(rows, cols, false)
+  }
+
+  /// Creates a new grid graph just like [Graph::grid], but
+  /// where each cell also has an undirected edge to the
+  /// cells diagonal to it.
+  ///
+  /// Panics if `rows * cols` is greater than `SIZE`.
+  /// ```
+  /// use const_graphs::Graph;
+  ///
+  /// // 0 1 2
+  /// // 3 4 5
+  /// const GRID: Graph<6> = Graph::grid_8(2, 3);
+  /// assert!(GRID.has_edge(0, 1));
+  /// assert!(GRID.has_edge(0, 4));
+  /// assert!(GRID.has_edge(2, 4));
+  /// assert!(!GRID.has_edge(0, 5));
+  /// ```
+  pub const fn grid_8(
+    rows: usize,
+    cols: usize,
+  ) -> Graph<SIZE> {
+    Graph::grid_with(rows, cols, true)
+  }
+}
+
+impl<const SIZE: usize> Default for Graph<SIZE> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Indexes the adjacency matrix of the graph, so
+/// `graph[(i, j)]` is whether there is an edge between `i`
+/// and `j`, just like [Graph::has_edge].
+/// ```
+/// use const_graphs::Graph;
+///
+/// let mut graph = Graph::<3>::new();
+/// graph.add_edge(0, 2);
+/// assert!(graph[(0, 2)]);
+/// assert!(!graph[(2, 0)]);
+/// ```
+impl<const SIZE: usize> Index<(usize, usize)>
+  for Graph<SIZE>
+{
+  type Output = bool;
+
+  fn index(&self, (i, j): (usize, usize)) -> &bool {
+    &self.data[i][j]
+  }
+}
+
+/// Mutably indexes the adjacency matrix of the graph, so
+/// `graph[(i, j)] = true` adds an edge between `i` and `j`,
+/// just like [Graph::add_edge].
+///
+/// The graph can't know what is done with the reference,
+/// so [Graph::count_edges] and [Graph::density] take
+/// `O(SIZE²)` time instead of constant time until the next
+/// change through a method like [Graph::add_edge], which
+/// counts the edges again once, or a call to
+/// [Graph::recount_edges].
+/// ```
+/// use const_graphs::Graph;
+///
+/// let mut graph = Graph::<3>::new();
+/// graph[(0, 2)] = true;
+/// graph[(1, 2)] = true;
+/// graph[(1, 0)] = true;
+/// graph[(1, 2)] = false;
+/// assert!(graph.has_edge(0, 2));
+/// assert!(!graph.has_edge(1, 2));
+/// assert_eq!(graph.count_edges(), 2);
+///
+/// // The methods work as usual.
+/// graph.remove_edge(1, 0);
+/// assert_eq!(graph.count_edges(), 1);
+/// ```
+impl<const SIZE: usize> IndexMut<(usize, usize)>
+  for Graph<SIZE>
+{
+  fn index_mut(
+    &mut self,
+    (i, j): (usize, usize),
+  ) -> &mut bool {
+    self.edge_count_stale = true;
+    &mut self.data[i][j]
+  }
+}
+
+/// Graphs are equal when they have the same edges.
+impl<const SIZE: usize> PartialEq for Graph<SIZE> {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
+/// Shows the adjacency matrix of the graph, graphs with
+/// more than 32 vertices are truncated.
+/// ```
+/// use const_graphs::Graph;
+///
+/// let mut graph = Graph::<3>::new();
+/// graph.add_edge(0, 1);
+/// graph.add_edge(2, 0);
+/// assert_eq!(
+///   format!("{graph:?}"),
+///   "  0 1 2\n\
+///    0 0 1 0\n\
+///    1 0 0 0\n\
+///    2 1 0 0"
+/// );
+/// ```
+impl<const SIZE: usize> fmt::Debug for Graph<SIZE> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let shown = SIZE.min(debug::MAX_VERTICES);
+    let width = debug::width(&shown.saturating_sub(1));
+
+    debug::write_header(f, shown, SIZE, width)?;
+
+    for i in 0..shown {
+      debug::write_label(f, i, shown)?;
+
+      for j in 0..shown {
+        write!(f, " ")?;
+        debug::write_padded(
+          f,
+          width,
+          &(self.data[i][j] as u8),
+        )?;
+      }
+
+      debug::write_row_end(f, i, shown, SIZE)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// An iterator over the neighbors of a vertex, returned by
+/// [Graph::neighbors].
+#[derive(Clone)]
+pub struct Neighbors<'a, const SIZE: usize> {
+  edges: &'a [bool; SIZE],
+  start: usize,
+  end: usize,
+}
+
+impl<const SIZE: usize> Iterator for Neighbors<'_, SIZE> {
+  type Item = usize;
+
+  fn next(&mut self) -> Option<usize> {
+    while self.start < self.end {
+      let neighbor = self.start;
+      self.start += 1;
+
+      if self.edges[neighbor] {
+        return Some(neighbor);
+      }
+    }
+
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(self.end - self.start))
+  }
+}
+
+impl<const SIZE: usize> DoubleEndedIterator
+  for Neighbors<'_, SIZE>
+{
+  fn next_back(&mut self) -> Option<usize> {
+    while self.start < self.end {
+      self.end -= 1;
+
+      if self.edges[self.end] {
+        return Some(self.end);
+      }
+    }
+
+    None
+  }
+}
+
+/// An iterator over the edges of a graph, returned by
+/// [Graph::edges].
+#[derive(Clone)]
+pub struct Edges<'a, const SIZE: usize> {
+  data: &'a [[bool; SIZE]; SIZE],
+  /// The index of the next edge in the flattened matrix.
+  position: usize,
+}
+
+impl<const SIZE: usize> Iterator for Edges<'_, SIZE> {
+  type Item = (usize, usize);
+
+  fn next(&mut self) -> Option<(usize, usize)> {
+    while self.position < SIZE * SIZE {
+      let (i, j) =
+        (self.position / SIZE, self.position % SIZE);
+      self.position += 1;
+
+      if self.data[i][j] {
+        return Some((i, j));
+      }
+    }
+
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(SIZE * SIZE - self.position))
   }
 }
@@ -17,11 +17,8 @@
 //!   queue.push_back(start);
 //!
 //!   while let Some(current) = queue.pop_front() {
-//!     for (neighbor, &has_edge) in graph
-//!                                      .get_edges(current)
-//!                                      .iter()
-//!                                      .enumerate() {
-//!       if has_edge && distance[neighbor] == usize::MAX {
+//!     for neighbor in graph.neighbors(current) {
+//!       if distance[neighbor] == usize::MAX {
 //!         distance[neighbor] = distance[current] + 1;
 //!         predecessor[neighbor] = current;
 //!         queue.push_back(neighbor);
@@ -62,12 +59,10 @@
 //!   queue.push_back(start);
 //!
 //!   while let Some(current) = queue.pop_front() {
-//!     for (neighbor, &edge) in graph
-//!                                      .get_edges(current)
-//!                                      .iter()
-//!                                      .enumerate() {
-//!       if edge.is_some() &&
-//!          distance[neighbor] == usize::MAX {
+//!     for (neighbor, _) in
+//!       graph.neighbors_weighted(current)
+//!     {
+//!       if distance[neighbor] == usize::MAX {
 //!         distance[neighbor] = distance[current] + 1;
 //!         predecessor[neighbor] = current;
 //!         queue.push_back(neighbor);
@@ -93,8 +88,6 @@
 //! ```
 
 #![no_std]
-#![feature(const_mut_refs)]
-#![feature(const_fn_floating_point_arithmetic)]
 #![deny(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(rustdoc::missing_crate_level_docs)]
@@ -103,8 +96,30 @@
 #![deny(rustdoc::invalid_rust_codeblocks)]
 #![deny(rustdoc::bare_urls)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod bit_graph;
+mod debug;
+mod error;
 mod graph;
+mod random;
+#[cfg(feature = "serde")]
+mod serde;
 mod weighted_graph;
 
-pub use self::graph::Graph;
-pub use self::weighted_graph::WeightedGraph;
+pub use self::bit_graph::{
+  bit_graph_words, BitGraph, BitRow, BitRowIter,
+};
+pub use self::error::GraphError;
+#[cfg(feature = "interop")]
+pub use self::error::ParseError;
+pub use self::graph::{Edges, Graph, Neighbors};
+pub use self::weighted_graph::{
+  WeightedEdges, WeightedGraph, WeightedNeighbors,
+};
+
+/// Runs the `rust` examples of the README as doctests.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
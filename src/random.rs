@@ -0,0 +1,36 @@
+/// The xorshift64 pseudorandom number generator by George
+/// Marsaglia, with the shifts 13, 7 and 17, used by the
+/// random graph generators so their results only depend on
+/// the seed.
+pub(crate) struct XorShift64 {
+  state: u64,
+}
+
+impl XorShift64 {
+  /// Creates a new generator from `seed`.
+  /// The state can't be 0, so that seed is replaced by
+  /// `0x9E3779B97F4A7C15`.
+  pub(crate) const fn new(seed: u64) -> XorShift64 {
+    XorShift64 {
+      state: if seed == 0 {
+        0x9E3779B97F4A7C15
+      } else {
+        seed
+      },
+    }
+  }
+
+  /// Returns the next number of the sequence.
+  pub(crate) const fn next_u64(&mut self) -> u64 {
+    self.state ^= self.state << 13;
+    self.state ^= self.state >> 7;
+    self.state ^= self.state << 17;
+    self.state
+  }
+
+  /// Returns a number in `[0, 1)` made of the 53 most
+  /// significant bits of the next number of the sequence.
+  pub(crate) const fn next_f64(&mut self) -> f64 {
+    (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+  }
+}
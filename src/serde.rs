@@ -0,0 +1,182 @@
+use crate::{Graph, GraphError, WeightedGraph};
+use ::serde::de::{Error, SeqAccess, Visitor};
+use ::serde::ser::SerializeSeq;
+use ::serde::{
+  Deserialize, Deserializer, Serialize, Serializer,
+};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Returns an error if the edge between `i` and `j` is out
+/// of bounds for a graph with `SIZE` vertices.
+fn check_edge<const SIZE: usize, E: Error>(
+  i: usize,
+  j: usize,
+) -> Result<(), E> {
+  GraphError::check_vertex(i, SIZE)
+    .and(GraphError::check_vertex(j, SIZE))
+    .map_err(E::custom)
+}
+
+/// Serializes the graph as a sequence of `(i, j)` edges.
+/// ```
+/// use const_graphs::Graph;
+///
+/// let mut graph = Graph::<100>::new();
+/// graph.add_edge(0, 1);
+/// graph.add_edge(99, 42);
+///
+/// let json = serde_json::to_string(&graph).unwrap();
+/// assert_eq!(json, "[[0,1],[99,42]]");
+/// assert!(
+///   serde_json::from_str::<Graph<100>>(&json).unwrap()
+///     == graph
+/// );
+///
+/// // Vertices out of bounds are rejected.
+/// assert!(
+///   serde_json::from_str::<Graph<42>>(&json).is_err()
+/// );
+/// ```
+impl<const SIZE: usize> Serialize for Graph<SIZE> {
+  fn serialize<S: Serializer>(
+    &self,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer
+      .serialize_seq(Some(self.edges().count()))?;
+
+    for edge in self.edges() {
+      seq.serialize_element(&edge)?;
+    }
+
+    seq.end()
+  }
+}
+
+impl<'de, const SIZE: usize> Deserialize<'de>
+  for Graph<SIZE>
+{
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    struct GraphVisitor<const SIZE: usize>;
+
+    impl<'de, const SIZE: usize> Visitor<'de>
+      for GraphVisitor<SIZE>
+    {
+      type Value = Graph<SIZE>;
+
+      fn expecting(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+      ) -> fmt::Result {
+        write!(f, "a sequence of edges")
+      }
+
+      fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+      ) -> Result<Graph<SIZE>, A::Error> {
+        let mut graph = Graph::new();
+
+        while let Some((i, j)) = seq.next_element()? {
+          check_edge::<SIZE, A::Error>(i, j)?;
+          graph.add_edge(i, j);
+        }
+
+        Ok(graph)
+      }
+    }
+
+    deserializer.deserialize_seq(GraphVisitor)
+  }
+}
+
+/// Serializes the graph as a sequence of `(i, j, weight)`
+/// edges.
+/// ```
+/// use const_graphs::WeightedGraph;
+///
+/// let mut graph = WeightedGraph::<100>::new();
+/// graph.add_edge(0, 1, 0.5);
+/// graph.add_edge(99, 42, 2.0);
+///
+/// let json = serde_json::to_string(&graph).unwrap();
+/// assert_eq!(json, "[[0,1,0.5],[99,42,2.0]]");
+/// assert!(
+///   serde_json::from_str::<WeightedGraph<100>>(&json)
+///     .unwrap()
+///     == graph
+/// );
+///
+/// // Vertices out of bounds are rejected.
+/// assert!(
+///   serde_json::from_str::<WeightedGraph<42>>(&json)
+///     .is_err()
+/// );
+/// ```
+impl<const SIZE: usize, W: Copy + Serialize> Serialize
+  for WeightedGraph<SIZE, W>
+{
+  fn serialize<S: Serializer>(
+    &self,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer
+      .serialize_seq(Some(self.edges().count()))?;
+
+    for edge in self.edges() {
+      seq.serialize_element(&edge)?;
+    }
+
+    seq.end()
+  }
+}
+
+impl<'de, const SIZE: usize, W: Copy + Deserialize<'de>>
+  Deserialize<'de> for WeightedGraph<SIZE, W>
+{
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    struct WeightedGraphVisitor<const SIZE: usize, W>(
+      PhantomData<W>,
+    );
+
+    impl<
+        'de,
+        const SIZE: usize,
+        W: Copy + Deserialize<'de>,
+      > Visitor<'de> for WeightedGraphVisitor<SIZE, W>
+    {
+      type Value = WeightedGraph<SIZE, W>;
+
+      fn expecting(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+      ) -> fmt::Result {
+        write!(f, "a sequence of weighted edges")
+      }
+
+      fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+      ) -> Result<WeightedGraph<SIZE, W>, A::Error> {
+        let mut graph = WeightedGraph::new();
+
+        while let Some((i, j, weight)) =
+          seq.next_element()?
+        {
+          check_edge::<SIZE, A::Error>(i, j)?;
+          graph.add_edge(i, j, weight);
+        }
+
+        Ok(graph)
+      }
+    }
+
+    deserializer
+      .deserialize_seq(WeightedGraphVisitor(PhantomData))
+  }
+}
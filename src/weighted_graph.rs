@@ -1,4 +1,28 @@
+use crate::debug;
+use crate::{Graph, GraphError};
+#[cfg(feature = "alloc")]
+use alloc::collections::BinaryHeap;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+use core::ops::{Index, IndexMut};
+
 /// Compile time graphs.
+///
+/// The weights can be any `Copy` type `W`, and default to
+/// `f32`.
+///
+/// Two graphs are equal if they have the same edges with
+/// equal weights. Note that a `NaN` weight is not equal to
+/// itself, so a graph with one isn't equal to itself
+/// either; this is why [Eq] is only implemented when `W`
+/// implements it.
 /// ```
 /// use const_graphs::WeightedGraph;
 ///
@@ -18,12 +42,22 @@
 ///
 /// // And, of course, let too:
 /// let graph3 = WeightedGraph::<SIZE, f64>::new();
+///
+/// // Integer weights work as well.
+/// const graph4: WeightedGraph<3, u32> = {
+///   let mut graph = WeightedGraph::new();
+///   graph.add_edge(0, 1, 7);
+///   graph
+/// };
+/// assert_eq!(graph4.get_edge(0, 1), Some(7));
+/// assert!(graph4 == graph4.transpose().transpose());
 /// ```
-pub struct WeightedGraph<const SIZE: usize, T: Copy = f32> {
-  data: [[Option<T>; SIZE]; SIZE],
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct WeightedGraph<const SIZE: usize, W: Copy = f32> {
+  data: [[Option<W>; SIZE]; SIZE],
 }
 
-impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
+impl<const SIZE: usize, W: Copy> WeightedGraph<SIZE, W> {
   /// Add an edge to the graph between `i` and `j`.
   /// ```
   /// use const_graphs::WeightedGraph;
@@ -37,7 +71,7 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     &mut self,
     i: usize,
     j: usize,
-    weight: T,
+    weight: W,
   ) {
     self.data[i][j] = Some(weight);
   }
@@ -57,7 +91,7 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     &mut self,
     i: usize,
     j: usize,
-    weight: T,
+    weight: W,
   ) {
     self.data[i][j] = Some(weight);
     self.data[j][i] = Some(weight);
@@ -98,6 +132,295 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     self.data[j][i] = None;
   }
 
+  /// Set the edge between `i` and `j` to `edge`, so
+  /// `Some(weight)` adds it and `None` removes it.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.set_edge(0, 1, Some(0.5));
+  /// assert_eq!(graph.get_edge(0, 1), Some(0.5));
+  ///
+  /// graph.set_edge(0, 1, None);
+  /// assert!(!graph.has_edge(0, 1));
+  /// ```
+  pub const fn set_edge(
+    &mut self,
+    i: usize,
+    j: usize,
+    edge: Option<W>,
+  ) {
+    self.data[i][j] = edge;
+  }
+
+  /// Replaces the edge between `i` and `j` with the result
+  /// of `f` applied to it, and returns the previous one.
+  /// Returning `None` from `f` removes the edge, and
+  /// returning `Some` adds it if there was none.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  ///
+  /// let increment =
+  ///   |edge: Option<f32>| edge.map(|w| w + 1.0);
+  /// let previous = graph.update_edge(0, 1, increment);
+  /// assert_eq!(previous, Some(0.5));
+  /// assert_eq!(graph.get_edge(0, 1), Some(1.5));
+  ///
+  /// // There is no edge to increment.
+  /// assert_eq!(graph.update_edge(1, 0, increment), None);
+  /// assert!(!graph.has_edge(1, 0));
+  ///
+  /// let previous = graph.update_edge(0, 1, |_| None);
+  /// assert_eq!(previous, Some(1.5));
+  /// assert!(!graph.has_edge(0, 1));
+  /// ```
+  /// See also [WeightedGraph::set_edge].
+  pub fn update_edge<F: FnOnce(Option<W>) -> Option<W>>(
+    &mut self,
+    i: usize,
+    j: usize,
+    f: F,
+  ) -> Option<W> {
+    let previous = self.data[i][j];
+    self.data[i][j] = f(previous);
+    previous
+  }
+
+  /// Add an edge to the graph between `i` and `j` with
+  /// `weight` for each `(i, j, weight)` in `edges`.
+  ///
+  /// Panics if any vertex is out of bounds, before adding
+  /// any edge after it.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edges(&[(0, 1, 0.5), (1, 2, 1.5)]);
+  /// assert_eq!(graph.get_edge(0, 1), Some(0.5));
+  /// assert_eq!(graph.get_edge(1, 2), Some(1.5));
+  /// assert!(!graph.has_edge(1, 0));
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edges(&[(0, 3, 0.5)]);
+  /// ```
+  /// See also [WeightedGraph::add_edges_undirected].
+  pub fn add_edges(&mut self, edges: &[(usize, usize, W)]) {
+    for &(i, j, weight) in edges {
+      assert!(
+        i < SIZE && j < SIZE,
+        "the edge ({i}, {j}) is out of bounds for a graph \
+         with {SIZE} vertices"
+      );
+
+      self.add_edge(i, j, weight);
+    }
+  }
+
+  /// Add an undirected edge to the graph between `i` and
+  /// `j` with `weight` for each `(i, j, weight)` in
+  /// `edges`.
+  ///
+  /// Panics if any vertex is out of bounds, before adding
+  /// any edge after it.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edges_undirected(&[
+  ///   (0, 1, 0.5),
+  ///   (1, 2, 1.5),
+  /// ]);
+  /// assert_eq!(graph.get_edge(1, 0), Some(0.5));
+  /// assert_eq!(graph.get_edge(2, 1), Some(1.5));
+  /// assert!(!graph.has_edge(0, 2));
+  /// ```
+  /// See also [WeightedGraph::add_edges].
+  pub fn add_edges_undirected(
+    &mut self,
+    edges: &[(usize, usize, W)],
+  ) {
+    for &(i, j, weight) in edges {
+      assert!(
+        i < SIZE && j < SIZE,
+        "the edge ({i}, {j}) is out of bounds for a graph \
+         with {SIZE} vertices"
+      );
+
+      self.add_edge_undirected(i, j, weight);
+    }
+  }
+
+  /// Add an edge to the graph between `i` and `j`, or
+  /// return an error if any of them is out of bounds.
+  /// ```
+  /// use const_graphs::{GraphError, WeightedGraph};
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// assert_eq!(graph.try_add_edge(0, 1, 0.5), Ok(()));
+  /// assert_eq!(graph.get_edge(0, 1), Some(0.5));
+  /// assert_eq!(
+  ///   graph.try_add_edge(0, 10, 0.5),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 10,
+  ///     size: 10,
+  ///   })
+  /// );
+  /// ```
+  /// See also [WeightedGraph::add_edge].
+  pub fn try_add_edge(
+    &mut self,
+    i: usize,
+    j: usize,
+    weight: W,
+  ) -> Result<(), GraphError> {
+    GraphError::check_vertex(i, SIZE)?;
+    GraphError::check_vertex(j, SIZE)?;
+
+    self.add_edge(i, j, weight);
+    Ok(())
+  }
+
+  /// Remove an edge from the graph between `i` and `j`, or
+  /// return an error if any of them is out of bounds.
+  /// ```
+  /// use const_graphs::{GraphError, WeightedGraph};
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// assert_eq!(graph.try_remove_edge(0, 1), Ok(()));
+  /// assert!(!graph.has_edge(0, 1));
+  /// assert_eq!(
+  ///   graph.try_remove_edge(10, 0),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 10,
+  ///     size: 10,
+  ///   })
+  /// );
+  /// ```
+  /// See also [WeightedGraph::remove_edge].
+  pub fn try_remove_edge(
+    &mut self,
+    i: usize,
+    j: usize,
+  ) -> Result<(), GraphError> {
+    GraphError::check_vertex(i, SIZE)?;
+    GraphError::check_vertex(j, SIZE)?;
+
+    self.remove_edge(i, j);
+    Ok(())
+  }
+
+  /// Checks whether there is an edge between `i` and `j`,
+  /// or returns an error if any of them is out of bounds.
+  /// ```
+  /// use const_graphs::{GraphError, WeightedGraph};
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// assert_eq!(graph.try_has_edge(0, 1), Ok(true));
+  /// assert_eq!(
+  ///   graph.try_has_edge(0, 10),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 10,
+  ///     size: 10,
+  ///   })
+  /// );
+  /// ```
+  /// See also [WeightedGraph::has_edge].
+  pub fn try_has_edge(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> Result<bool, GraphError> {
+    GraphError::check_vertex(i, SIZE)?;
+    GraphError::check_vertex(j, SIZE)?;
+
+    Ok(self.has_edge(i, j))
+  }
+
+  /// Remove every edge from and to `vertex`.
+  /// The number of vertices of the graph is fixed, so the
+  /// vertex isn't removed, it just becomes isolated.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 1.5);
+  /// graph.add_edge(2, 0, 2.5);
+  /// graph.remove_vertex(1);
+  /// assert!(!graph.has_edge(0, 1));
+  /// assert!(!graph.has_edge(1, 2));
+  /// assert!(graph.has_edge(2, 0));
+  /// ```
+  pub const fn remove_vertex(&mut self, vertex: usize) {
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      self.data[vertex][neighbor] = None;
+      self.data[neighbor][vertex] = None;
+
+      neighbor += 1;
+    }
+  }
+
+  /// Add a self-loop to the graph on `vertex`, that is, an
+  /// edge between `vertex` and itself.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_self_loop(3, 0.5);
+  /// assert_eq!(graph.get_edge(3, 3), Some(0.5));
+  /// ```
+  /// See also [WeightedGraph::remove_self_loops].
+  pub const fn add_self_loop(
+    &mut self,
+    vertex: usize,
+    weight: W,
+  ) {
+    self.data[vertex][vertex] = Some(weight);
+  }
+
+  /// Checks whether there is a self-loop on `vertex`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_edge(3, 3, 0.5);
+  /// assert!(graph.has_self_loop(3));
+  /// assert!(!graph.has_self_loop(4));
+  /// ```
+  pub const fn has_self_loop(&self, vertex: usize) -> bool {
+    self.data[vertex][vertex].is_some()
+  }
+
+  /// Remove every self-loop from the graph, leaving the
+  /// other edges untouched.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_self_loop(3, 0.5);
+  /// graph.add_edge(3, 4, 1.5);
+  /// graph.remove_self_loops();
+  /// assert!(!graph.has_self_loop(3));
+  /// assert!(graph.has_edge(3, 4));
+  /// ```
+  pub const fn remove_self_loops(&mut self) {
+    let mut vertex = 0;
+    while vertex < SIZE {
+      self.data[vertex][vertex] = None;
+
+      vertex += 1;
+    }
+  }
+
   /// Gets the optional edge between `i` and `j`.
   /// ```
   /// use const_graphs::WeightedGraph;
@@ -110,10 +433,33 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     &self,
     i: usize,
     j: usize,
-  ) -> Option<T> {
+  ) -> Option<W> {
     self.data[i][j]
   }
 
+  /// Gets the weight of the edge between `i` and `j`, or
+  /// `default` if there is none.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_edge(0, 1, 16.0);
+  /// assert_eq!(graph.get_edge_or(0, 1, 0.0), 16.0);
+  /// assert_eq!(graph.get_edge_or(1, 0, 0.0), 0.0);
+  /// ```
+  /// See also [WeightedGraph::get_edge_or_infinity].
+  pub const fn get_edge_or(
+    &self,
+    i: usize,
+    j: usize,
+    default: W,
+  ) -> W {
+    match self.data[i][j] {
+      Some(weight) => weight,
+      None => default,
+    }
+  }
+
   /// Checks whether there is an edge between `i` and `j`.
   /// ```
   /// use const_graphs::WeightedGraph;
@@ -126,6 +472,26 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     self.data[i][j].is_some()
   }
 
+  /// Returns a reference to the adjacency matrix of the
+  /// graph, where the element `[i][j]` is the optional edge
+  /// between `i` and `j`, without copying it.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<2>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// assert_eq!(
+  ///   graph.as_matrix(),
+  ///   &[[None, Some(0.5)], [None, None]]
+  /// );
+  /// ```
+  /// See also [WeightedGraph::from_matrix].
+  pub const fn as_matrix(
+    &self,
+  ) -> &[[Option<W>; SIZE]; SIZE] {
+    &self.data
+  }
+
   /// Returns an array where the ith element is the optional
   /// edge between `vertex` and `i`.
   /// ```
@@ -142,10 +508,38 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
   pub const fn get_edges(
     &self,
     vertex: usize,
-  ) -> &[Option<T>; SIZE] {
+  ) -> &[Option<W>; SIZE] {
     &self.data[vertex]
   }
 
+  /// Returns a mutable reference to the array where the ith
+  /// element is the optional edge between `vertex` and `i`,
+  /// so a whole row can be changed at once.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut other = WeightedGraph::<3>::new();
+  /// other.add_edge(1, 0, 0.5);
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 2, 1.5);
+  /// *graph.get_edges_mut(0) = *other.get_edges(1);
+  /// assert_eq!(
+  ///   graph.get_edges(0),
+  ///   &[Some(0.5), None, None]
+  /// );
+  ///
+  /// graph.get_edges_mut(0)[1] = Some(2.5);
+  /// assert_eq!(graph.get_edge(0, 1), Some(2.5));
+  /// ```
+  /// See also [WeightedGraph::get_edges].
+  pub const fn get_edges_mut(
+    &mut self,
+    vertex: usize,
+  ) -> &mut [Option<W>; SIZE] {
+    &mut self.data[vertex]
+  }
+
   /// Returns an array where the ith element is the optional
   /// edge between `i` and `vertex`.
   /// This is useful in a few graph algorithms where you
@@ -165,7 +559,7 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
   pub const fn get_inverse_edges(
     &self,
     vertex: usize,
-  ) -> [Option<T>; SIZE] {
+  ) -> [Option<W>; SIZE] {
     let mut edges = [None; SIZE];
 
     let mut neighbor = 0;
@@ -178,6 +572,163 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     edges
   }
 
+  /// Returns an iterator over the pairs `(i, weight)` such
+  /// that there is an edge between `vertex` and `i`, in
+  /// ascending order of `i`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(0, 3, 2.0);
+  /// assert!(graph
+  ///   .neighbors_weighted(0)
+  ///   .eq([(1, 0.5), (3, 2.0)]));
+  /// assert!(graph
+  ///   .neighbors_weighted(0)
+  ///   .rev()
+  ///   .eq([(3, 2.0), (1, 0.5)]));
+  /// ```
+  /// See also [WeightedGraph::get_edges].
+  pub const fn neighbors_weighted(
+    &self,
+    vertex: usize,
+  ) -> WeightedNeighbors<'_, SIZE, W> {
+    WeightedNeighbors {
+      edges: &self.data[vertex],
+      start: 0,
+      end: SIZE,
+    }
+  }
+
+  /// Returns an iterator over the triples `(i, j, weight)`
+  /// such that there is an edge between `i` and `j`, in
+  /// row-major order.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(2, 0, 0.5);
+  /// graph.add_edge(0, 1, 1.5);
+  /// assert!(graph.edges().eq([(0, 1, 1.5), (2, 0, 0.5)]));
+  /// assert_eq!(graph.edges().count(), 2);
+  /// ```
+  /// See also [WeightedGraph::from_edges].
+  pub const fn edges(&self) -> WeightedEdges<'_, SIZE, W> {
+    WeightedEdges {
+      data: &self.data,
+      position: 0,
+    }
+  }
+
+  /// Returns the number of edges leaving `vertex`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(0, 2, 1.5);
+  /// assert_eq!(graph.out_degree(0), 2);
+  /// assert_eq!(graph.out_degree(1), 0);
+  /// ```
+  /// See also [WeightedGraph::in_degree] and
+  /// [WeightedGraph::degree].
+  pub const fn out_degree(&self, vertex: usize) -> usize {
+    let mut degree = 0;
+
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      if self.data[vertex][neighbor].is_some() {
+        degree += 1;
+      }
+
+      neighbor += 1;
+    }
+
+    degree
+  }
+
+  /// Returns the number of edges arriving at `vertex`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 2, 0.5);
+  /// graph.add_edge(1, 2, 1.5);
+  /// assert_eq!(graph.in_degree(2), 2);
+  /// assert_eq!(graph.in_degree(0), 0);
+  /// ```
+  /// See also [WeightedGraph::out_degree] and
+  /// [WeightedGraph::degree].
+  pub const fn in_degree(&self, vertex: usize) -> usize {
+    let mut degree = 0;
+
+    let mut neighbor = 0;
+    while neighbor < SIZE {
+      if self.data[neighbor][vertex].is_some() {
+        degree += 1;
+      }
+
+      neighbor += 1;
+    }
+
+    degree
+  }
+
+  /// Returns the sum of the in and out degrees of
+  /// `vertex`.
+  /// A self-loop is both an in and an out edge, so it is
+  /// counted twice.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge_undirected(0, 1, 0.2);
+  /// graph.add_edge(2, 0, 0.4);
+  /// assert_eq!(graph.degree(0), 3);
+  ///
+  /// graph.add_edge(2, 2, 0.8);
+  /// assert_eq!(graph.degree(2), 3);
+  /// ```
+  /// See also [WeightedGraph::out_degree] and
+  /// [WeightedGraph::in_degree].
+  pub const fn degree(&self, vertex: usize) -> usize {
+    self.out_degree(vertex) + self.in_degree(vertex)
+  }
+
+  /// Returns the order of the graph, that is, its number of
+  /// vertices, which is always `SIZE`.
+  /// Not to be confused with [WeightedGraph::size], the
+  /// number of edges.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let graph = WeightedGraph::<3>::new();
+  /// assert_eq!(graph.order(), 3);
+  /// ```
+  pub const fn order(&self) -> usize {
+    SIZE
+  }
+
+  /// Returns the size of the graph, that is, its number of
+  /// directed edges, just like
+  /// [WeightedGraph::count_edges], so self-loops are not
+  /// counted.
+  /// Not to be confused with [WeightedGraph::order], the
+  /// number of vertices, which is what `SIZE` is.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge_undirected(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 1.5);
+  /// assert_eq!(graph.size(), 3);
+  /// assert_eq!(graph.order(), 3);
+  /// ```
+  pub const fn size(&self) -> usize {
+    self.count_edges()
+  }
+
   /// Returns the maximum number of edges of a graph.
   /// ```
   /// use const_graphs::WeightedGraph;
@@ -196,26 +747,29 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
     SIZE * (SIZE - 1)
   }
 
-  /// Returns the density of a graph, that is, the ratio
-  /// between the number of edges and the maximum number of
-  /// possible edges.
+  /// Returns the number of directed edges of a graph.
+  /// Just like in [WeightedGraph::max_number_of_edges],
+  /// self-loops are not counted.
+  /// Unlike [Graph::count_edges], the count isn't kept up
+  /// to date, so this goes through the whole matrix, in
+  /// `O(SIZE²)` time.
   /// ```
   /// use const_graphs::WeightedGraph;
   ///
   /// let mut graph = WeightedGraph::<3>::new();
   /// graph.add_edge_undirected(0, 1, 0.1);
-  /// graph.add_edge_undirected(0, 2, 1.1);
-  /// graph.add_edge_undirected(1, 2, 0.5);
-  /// assert_eq!(graph.density(), 1.0);
+  /// graph.add_edge(1, 2, 1.1);
+  /// graph.add_edge(2, 2, 0.5);
+  /// assert_eq!(graph.count_edges(), 3);
   /// ```
-  pub const fn density(&self) -> f32 {
+  pub const fn count_edges(&self) -> usize {
     let mut edges = 0;
 
     let mut i = 0;
     while i < SIZE {
       let mut j = 0;
       while j < SIZE {
-        if self.data[i][j].is_some() {
+        if i != j && self.data[i][j].is_some() {
           edges += 1;
         }
 
@@ -224,11 +778,491 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
       i += 1;
     }
 
-    edges as f32 / self.max_number_of_edges() as f32
+    edges
   }
 
-  /// Remove all edges from the graph.
-  /// ```
+  /// Returns the density of a graph, that is, the ratio
+  /// between the number of edges and the maximum number of
+  /// possible edges.
+  /// Just like [WeightedGraph::count_edges], this takes
+  /// `O(SIZE²)` time, unlike [Graph::density].
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge_undirected(0, 1, 0.1);
+  /// graph.add_edge_undirected(0, 2, 1.1);
+  /// graph.add_edge_undirected(1, 2, 0.5);
+  /// assert_eq!(graph.density(), 1.0);
+  /// ```
+  pub const fn density(&self) -> f32 {
+    self.count_edges() as f32
+      / self.max_number_of_edges() as f32
+  }
+
+  /// Returns a new graph with every edge reversed, that is,
+  /// the edge between `j` and `i` in the result has the
+  /// weight of the edge between `i` and `j` in the graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 2, 0.3);
+  /// let transposed = graph.transpose();
+  /// assert_eq!(transposed.get_edge(2, 0), Some(0.3));
+  /// assert!(!transposed.has_edge(0, 2));
+  /// ```
+  /// See also [WeightedGraph::get_inverse_edges].
+  pub const fn transpose(&self) -> WeightedGraph<SIZE, W> {
+    let mut transposed = WeightedGraph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        transposed.data[j][i] = self.data[i][j];
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    transposed
+  }
+
+  /// Returns a new graph where each vertex `v` becomes
+  /// `permutation[v]`, so there is an edge between
+  /// `permutation[i]` and `permutation[j]` iff there is one
+  /// between `i` and `j` in the graph.
+  ///
+  /// Panics if `permutation` isn't a permutation of the
+  /// vertices, see [WeightedGraph::try_relabel].
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 1.5);
+  /// let relabeled = graph.relabel(&[2, 0, 1]);
+  /// assert_eq!(relabeled.get_edge(2, 0), Some(0.5));
+  /// assert_eq!(relabeled.get_edge(0, 1), Some(1.5));
+  /// assert_eq!(relabeled.count_edges(), 2);
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let graph = WeightedGraph::<3>::new();
+  /// graph.relabel(&[0, 1, 1]);
+  /// ```
+  pub const fn relabel(
+    &self,
+    permutation: &[usize; SIZE],
+  ) -> WeightedGraph<SIZE, W> {
+    match self.try_relabel(permutation) {
+      Ok(relabeled) => relabeled,
+      Err(_) => panic!("not a permutation of the vertices"),
+    }
+  }
+
+  /// Returns a new graph where each vertex `v` becomes
+  /// `permutation[v]`, just like [WeightedGraph::relabel],
+  /// or an error if `permutation` has a vertex that is out
+  /// of bounds or repeated.
+  /// ```
+  /// use const_graphs::{GraphError, WeightedGraph};
+  ///
+  /// let graph = WeightedGraph::<3>::new();
+  /// assert!(graph.try_relabel(&[1, 2, 0]).is_ok());
+  /// assert_eq!(
+  ///   graph.try_relabel(&[0, 2, 2]),
+  ///   Err(GraphError::RepeatedVertex { vertex: 2 })
+  /// );
+  /// assert_eq!(
+  ///   graph.try_relabel(&[0, 1, 3]),
+  ///   Err(GraphError::VertexOutOfBounds {
+  ///     vertex: 3,
+  ///     size: 3,
+  ///   })
+  /// );
+  /// ```
+  pub const fn try_relabel(
+    &self,
+    permutation: &[usize; SIZE],
+  ) -> Result<WeightedGraph<SIZE, W>, GraphError> {
+    if let Err(error) =
+      GraphError::check_permutation(permutation)
+    {
+      return Err(error);
+    }
+
+    let mut relabeled = WeightedGraph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        relabeled.data[permutation[i]][permutation[j]] =
+          self.data[i][j];
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    Ok(relabeled)
+  }
+
+  /// Swaps the vertices `u` and `v` in place, that is,
+  /// every edge from or to `u` now goes from or to `v`, and
+  /// the other way around, including the self-loops and
+  /// the edges between them.
+  /// It's the same as [WeightedGraph::relabel] with a
+  /// permutation that only swaps them, without creating a
+  /// new graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_self_loop(0, 1.5);
+  /// graph.add_edge(2, 0, 2.5);
+  /// graph.swap_vertices(0, 1);
+  /// assert_eq!(graph.get_edge(1, 0), Some(0.5));
+  /// assert_eq!(graph.get_edge(1, 1), Some(1.5));
+  /// assert_eq!(graph.get_edge(2, 1), Some(2.5));
+  /// assert!(!graph.has_edge(0, 1));
+  /// ```
+  pub const fn swap_vertices(
+    &mut self,
+    u: usize,
+    v: usize,
+  ) {
+    // Swapping the rows and then the columns also swaps
+    // the diagonal entries.
+    let mut k = 0;
+    while k < SIZE {
+      let edge = self.data[u][k];
+      self.data[u][k] = self.data[v][k];
+      self.data[v][k] = edge;
+
+      k += 1;
+    }
+
+    let mut k = 0;
+    while k < SIZE {
+      let edge = self.data[k][u];
+      self.data[k][u] = self.data[k][v];
+      self.data[k][v] = edge;
+
+      k += 1;
+    }
+  }
+
+  /// Returns a new graph where `v` is merged into `u`, that
+  /// is, every edge from or to `v` now goes from or to `u`
+  /// instead, and the edges between them, which would
+  /// become self-loops, are dropped.
+  /// When both `u` and `v` have an edge to or from the same
+  /// vertex, their weights are combined with
+  /// `combine(weight_u, weight_v)`, so passing e.g.
+  /// `f32::min`, `f32::max` or `|a, b| a + b` picks the
+  /// policy.
+  /// The number of vertices of the graph is fixed, so `v`
+  /// isn't removed, it just becomes isolated, and
+  /// contracting a vertex into itself changes nothing.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(0, 2, 1.0);
+  /// graph.add_edge(1, 2, 2.0);
+  /// graph.add_edge(3, 1, 3.0);
+  /// let contracted = graph.contract(0, 1, |a, b| a + b);
+  /// assert_eq!(contracted.get_edge(0, 2), Some(3.0));
+  /// assert_eq!(contracted.get_edge(3, 0), Some(3.0));
+  /// assert_eq!(contracted.get_edge(0, 0), None);
+  /// assert_eq!(contracted.degree(1), 0);
+  /// ```
+  pub fn contract<F: FnMut(W, W) -> W>(
+    &self,
+    u: usize,
+    v: usize,
+    mut combine: F,
+  ) -> WeightedGraph<SIZE, W> {
+    let mut contracted = *self;
+    if u == v {
+      return contracted;
+    }
+
+    let mut merge =
+      |a: Option<W>, b: Option<W>| match (a, b) {
+        (Some(a), Some(b)) => Some(combine(a, b)),
+        (a, b) => a.or(b),
+      };
+
+    for w in 0..SIZE {
+      if w != u && w != v {
+        contracted.data[u][w] =
+          merge(self.data[u][w], self.data[v][w]);
+        contracted.data[w][u] =
+          merge(self.data[w][u], self.data[w][v]);
+      }
+    }
+
+    contracted.remove_vertex(v);
+    contracted
+  }
+
+  /// Reverses every edge of the graph in place, just like
+  /// [WeightedGraph::transpose] but without creating a new
+  /// graph.
+  /// Doing it twice gives back the original graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 2, 0.3);
+  /// graph.transpose_mut();
+  /// assert!(graph.has_edge(2, 0));
+  /// assert!(!graph.has_edge(0, 2));
+  ///
+  /// graph.transpose_mut();
+  /// assert!(graph.has_edge(0, 2));
+  /// ```
+  pub const fn transpose_mut(&mut self) {
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = i + 1;
+      while j < SIZE {
+        let edge = self.data[i][j];
+        self.data[i][j] = self.data[j][i];
+        self.data[j][i] = edge;
+
+        j += 1;
+      }
+      i += 1;
+    }
+  }
+
+  /// Makes the graph undirected in place, so the edges
+  /// between `i` and `j` and between `j` and `i` have the
+  /// same weight.
+  /// When only one of them exists, its weight is copied to
+  /// the other, and when both do, they are replaced by
+  /// `merge(weight_ij, weight_ji)` for `i < j`, so passing
+  /// e.g. `f32::min`, `f32::max` or `|first, _| first`
+  /// picks the policy.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 0, 1.5);
+  /// graph.add_edge(2, 1, 2.5);
+  /// graph.symmetrize_with(f32::min);
+  /// assert_eq!(graph.get_edge(1, 0), Some(0.5));
+  /// assert_eq!(graph.get_edge(1, 2), Some(2.5));
+  /// assert_eq!(graph.count_edges(), 4);
+  /// ```
+  pub fn symmetrize_with<F: FnMut(W, W) -> W>(
+    &mut self,
+    mut merge: F,
+  ) {
+    for i in 0..SIZE {
+      for j in i + 1..SIZE {
+        let edge = match (self.data[i][j], self.data[j][i])
+        {
+          (Some(ij), Some(ji)) => Some(merge(ij, ji)),
+          (ij, ji) => ij.or(ji),
+        };
+        self.data[i][j] = edge;
+        self.data[j][i] = edge;
+      }
+    }
+  }
+
+  /// Returns a new graph with the same edges as the graph,
+  /// without their weights.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// let unweighted = graph.to_unweighted();
+  /// assert!(unweighted.has_edge(0, 1));
+  /// assert!(!unweighted.has_edge(1, 0));
+  /// ```
+  /// See also [Graph::to_weighted].
+  pub const fn to_unweighted(&self) -> Graph<SIZE> {
+    let mut data = [[false; SIZE]; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        data[i][j] = self.data[i][j].is_some();
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    Graph::from_matrix(data)
+  }
+
+  /// Returns the adjacency list of the graph, where the
+  /// `v`th list has the neighbors of `v` in ascending
+  /// order, with the weights of the edges to them.
+  /// Unlike most methods, it allocates, so it requires the
+  /// `alloc` feature.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 2, 0.5);
+  /// graph.add_edge(0, 1, 1.5);
+  /// graph.add_edge(2, 0, 2.5);
+  /// assert_eq!(
+  ///   graph.to_adjacency_list(),
+  ///   [vec![(1, 1.5), (2, 0.5)], vec![], vec![(0, 2.5)]]
+  /// );
+  /// ```
+  /// See also [WeightedGraph::neighbors_weighted].
+  #[cfg(feature = "alloc")]
+  pub fn to_adjacency_list(&self) -> Vec<Vec<(usize, W)>> {
+    (0..SIZE)
+      .map(|vertex| {
+        self.neighbors_weighted(vertex).collect()
+      })
+      .collect()
+  }
+
+  /// Returns the graph in the compressed sparse row (CSR)
+  /// format, as the row pointers, the column indices and
+  /// the weights.
+  /// The neighbors of `v` are in ascending order in
+  /// `columns[row_pointers[v]..row_pointers[v + 1]]`, and
+  /// the weights of the edges to them are at the same
+  /// positions of `weights`, so `row_pointers` has
+  /// `SIZE + 1` elements, starting at `0` and ending at the
+  /// number of edges, self-loops included.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 2, 0.5);
+  /// graph.add_edge(0, 1, 1.5);
+  /// graph.add_edge(2, 2, 2.5);
+  /// let (row_pointers, columns, weights) = graph.to_csr();
+  /// assert_eq!(row_pointers, [0, 2, 2, 3]);
+  /// assert_eq!(columns, [1, 2, 2]);
+  /// assert_eq!(weights, [1.5, 0.5, 2.5]);
+  /// ```
+  /// See also [WeightedGraph::to_adjacency_list].
+  #[cfg(feature = "alloc")]
+  pub fn to_csr(&self) -> (Vec<usize>, Vec<usize>, Vec<W>) {
+    let mut row_pointers = Vec::with_capacity(SIZE + 1);
+    let mut columns = Vec::new();
+    let mut weights = Vec::new();
+
+    row_pointers.push(0);
+    for vertex in 0..SIZE {
+      for (neighbor, weight) in
+        self.neighbors_weighted(vertex)
+      {
+        columns.push(neighbor);
+        weights.push(weight);
+      }
+      row_pointers.push(columns.len());
+    }
+
+    (row_pointers, columns, weights)
+  }
+
+  /// Returns a new graph with the same edges as the graph,
+  /// with `f` applied to each of their weights.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 2.0);
+  /// let doubled = graph.map_weights(|w| w * 2.0);
+  /// assert_eq!(doubled.get_edge(0, 1), Some(1.0));
+  /// assert_eq!(doubled.get_edge(1, 2), Some(4.0));
+  /// assert!(!doubled.has_edge(0, 2));
+  ///
+  /// // The weights can change their type.
+  /// let rounded = graph.map_weights(|w| w as u32);
+  /// assert_eq!(rounded.get_edge(1, 2), Some(2));
+  /// ```
+  /// See also [WeightedGraph::map_weights_in_place].
+  pub fn map_weights<U: Copy, F: FnMut(W) -> U>(
+    &self,
+    mut f: F,
+  ) -> WeightedGraph<SIZE, U> {
+    let mut data = [[None; SIZE]; SIZE];
+
+    for (i, j, weight) in self.edges() {
+      data[i][j] = Some(f(weight));
+    }
+
+    WeightedGraph::from_matrix(data)
+  }
+
+  /// Returns a new graph with only the edges between `i`
+  /// and `j` for which `keep(i, j, weight)` is true.
+  /// `keep` is only called for the edges present in the
+  /// graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.7);
+  /// graph.add_edge(2, 1, 0.3);
+  /// let filtered = graph.filter_edges(|_, _, w| w > 0.5);
+  /// assert_eq!(filtered.get_edge(0, 1), Some(0.7));
+  /// assert!(!filtered.has_edge(2, 1));
+  /// ```
+  pub fn filter_edges<F: FnMut(usize, usize, W) -> bool>(
+    &self,
+    mut keep: F,
+  ) -> WeightedGraph<SIZE, W> {
+    let mut filtered = WeightedGraph::new();
+
+    for (i, j, weight) in self.edges() {
+      if keep(i, j, weight) {
+        filtered.add_edge(i, j, weight);
+      }
+    }
+
+    filtered
+  }
+
+  /// Applies `f` to the weight of each edge of the graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.map_weights_in_place(|weight| -weight);
+  /// assert_eq!(graph.get_edge(0, 1), Some(-0.5));
+  /// assert!(!graph.has_edge(1, 0));
+  /// ```
+  /// See also [WeightedGraph::map_weights].
+  pub fn map_weights_in_place<F: FnMut(W) -> W>(
+    &mut self,
+    mut f: F,
+  ) {
+    for weight in self.data.iter_mut().flatten().flatten() {
+      *weight = f(*weight);
+    }
+  }
+
+  /// Remove all edges from the graph.
+  /// ```
   /// use const_graphs::WeightedGraph;
   ///
   /// let mut graph = WeightedGraph::<3>::new();
@@ -259,9 +1293,1817 @@ impl<const SIZE: usize, T: Copy> WeightedGraph<SIZE, T> {
   /// const SIZE: usize = 10;
   /// let graph = WeightedGraph::<SIZE>::new();
   /// ```
-  pub const fn new() -> WeightedGraph<SIZE, T> {
+  pub const fn new() -> WeightedGraph<SIZE, W> {
     WeightedGraph {
       data: [[None; SIZE]; SIZE],
     }
   }
+
+  /// Creates a new weighted graph from an adjacency matrix,
+  /// where `data[i][j]` is the optional edge between `i`
+  /// and `j`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// const GRAPH: WeightedGraph<3> =
+  ///   WeightedGraph::from_matrix([
+  ///     [None, Some(0.5), None],
+  ///     [None, None, Some(1.5)],
+  ///     [None, None, None],
+  ///   ]);
+  /// assert_eq!(GRAPH.get_edge(0, 1), Some(0.5));
+  /// assert_eq!(GRAPH.get_edge(1, 2), Some(1.5));
+  /// assert!(!GRAPH.has_edge(2, 0));
+  /// ```
+  pub const fn from_matrix(
+    data: [[Option<W>; SIZE]; SIZE],
+  ) -> WeightedGraph<SIZE, W> {
+    WeightedGraph { data }
+  }
+
+  /// Creates a new weighted graph where the edge between
+  /// `i` and `j` is `f(i, j)`, calling it once for each
+  /// pair of vertices, in row order.
+  /// Unlike [WeightedGraph::from_matrix], it can't be used
+  /// in const contexts, since it takes a closure.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let graph = WeightedGraph::<3>::from_fn(|i, j| {
+  ///   (i < j).then(|| (j - i) as f32 / 2.0)
+  /// });
+  /// assert_eq!(graph.get_edge(0, 2), Some(1.0));
+  /// assert_eq!(graph.get_edge(1, 2), Some(0.5));
+  /// assert_eq!(graph.get_edge(2, 1), None);
+  /// ```
+  pub fn from_fn<F: FnMut(usize, usize) -> Option<W>>(
+    mut f: F,
+  ) -> WeightedGraph<SIZE, W> {
+    let mut graph = WeightedGraph::new();
+
+    for (i, row) in graph.data.iter_mut().enumerate() {
+      for (j, edge) in row.iter_mut().enumerate() {
+        *edge = f(i, j);
+      }
+    }
+
+    graph
+  }
+
+  /// Creates a new weighted graph with an edge between `i`
+  /// and `j` for each `(i, j, weight)` in `edges`.
+  ///
+  /// Panics if any vertex is out of bounds.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let graph = WeightedGraph::<3>::from_edges(&[
+  ///   (0, 1, 0.5),
+  ///   (1, 2, 1.5),
+  /// ]);
+  /// assert_eq!(graph.get_edge(0, 1), Some(0.5));
+  /// assert_eq!(graph.get_edge(1, 2), Some(1.5));
+  /// assert!(!graph.has_edge(1, 0));
+  /// ```
+  /// ```should_panic
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let graph =
+  ///   WeightedGraph::<3>::from_edges(&[(3, 0, 0.5)]);
+  /// ```
+  /// See also [WeightedGraph::from_edges_undirected].
+  pub fn from_edges(
+    edges: &[(usize, usize, W)],
+  ) -> WeightedGraph<SIZE, W> {
+    let mut graph = WeightedGraph::new();
+    graph.add_edges(edges);
+    graph
+  }
+
+  /// Creates a new weighted graph with an undirected edge
+  /// between `i` and `j` for each `(i, j, weight)` in
+  /// `edges`.
+  ///
+  /// Panics if any vertex is out of bounds.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let graph = WeightedGraph::<3>::from_edges_undirected(
+  ///   &[(0, 1, 0.5), (1, 2, 1.5)],
+  /// );
+  /// assert_eq!(graph.get_edge(0, 1), Some(0.5));
+  /// assert_eq!(graph.get_edge(1, 0), Some(0.5));
+  /// assert!(!graph.has_edge(0, 2));
+  /// ```
+  /// See also [WeightedGraph::from_edges].
+  pub fn from_edges_undirected(
+    edges: &[(usize, usize, W)],
+  ) -> WeightedGraph<SIZE, W> {
+    let mut graph = WeightedGraph::new();
+    graph.add_edges_undirected(edges);
+    graph
+  }
+
+  /// Creates a new complete graph, that is, a graph with an
+  /// edge with `weight` between every pair of different
+  /// vertices, and no self-loops.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// const K5: WeightedGraph<5> =
+  ///   WeightedGraph::complete(0.5);
+  /// assert_eq!(K5.get_edge(0, 4), Some(0.5));
+  /// assert!(!K5.has_edge(2, 2));
+  /// assert_eq!(K5.density(), 1.0);
+  /// ```
+  pub const fn complete(
+    weight: W,
+  ) -> WeightedGraph<SIZE, W> {
+    let mut graph = WeightedGraph::new();
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if i != j {
+          graph.data[i][j] = Some(weight);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    graph
+  }
+}
+
+impl<const SIZE: usize, W: Copy> Default
+  for WeightedGraph<SIZE, W>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Indexes the adjacency matrix of the graph, so
+/// `graph[(i, j)]` is the optional edge between `i` and
+/// `j`, just like [WeightedGraph::get_edge].
+/// ```
+/// use const_graphs::WeightedGraph;
+///
+/// let mut graph = WeightedGraph::<3>::new();
+/// graph.add_edge(0, 2, 0.5);
+/// assert_eq!(graph[(0, 2)], Some(0.5));
+/// assert_eq!(graph[(2, 0)], None);
+/// ```
+impl<const SIZE: usize, W: Copy> Index<(usize, usize)>
+  for WeightedGraph<SIZE, W>
+{
+  type Output = Option<W>;
+
+  fn index(&self, (i, j): (usize, usize)) -> &Option<W> {
+    &self.data[i][j]
+  }
+}
+
+/// Mutably indexes the adjacency matrix of the graph, so
+/// `graph[(i, j)] = Some(weight)` adds an edge between `i`
+/// and `j`, and `graph[(i, j)] = None` removes it.
+/// ```
+/// use const_graphs::WeightedGraph;
+///
+/// let mut graph = WeightedGraph::<3>::new();
+/// graph[(0, 2)] = Some(0.5);
+/// assert!(graph.has_edge(0, 2));
+///
+/// if let Some(weight) = &mut graph[(0, 2)] {
+///   *weight *= 2.0;
+/// }
+/// assert_eq!(graph.get_edge(0, 2), Some(1.0));
+///
+/// graph[(0, 2)] = None;
+/// assert!(!graph.has_edge(0, 2));
+/// ```
+impl<const SIZE: usize, W: Copy> IndexMut<(usize, usize)>
+  for WeightedGraph<SIZE, W>
+{
+  fn index_mut(
+    &mut self,
+    (i, j): (usize, usize),
+  ) -> &mut Option<W> {
+    &mut self.data[i][j]
+  }
+}
+
+/// Shows the weights of the adjacency matrix of the graph,
+/// with a `.` where there is no edge, graphs with more than
+/// 32 vertices are truncated.
+/// ```
+/// use const_graphs::WeightedGraph;
+///
+/// let mut graph = WeightedGraph::<3>::new();
+/// graph.add_edge(0, 1, 0.5);
+/// graph.add_edge(2, 0, 10.0);
+/// assert_eq!(
+///   format!("{graph:?}"),
+///   "     0    1    2\n\
+///    0    .  0.5    .\n\
+///    1    .    .    .\n\
+///    2 10.0    .    ."
+/// );
+/// ```
+impl<const SIZE: usize, W: Copy + fmt::Debug> fmt::Debug
+  for WeightedGraph<SIZE, W>
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Shown where there is no edge.
+    struct Empty;
+
+    impl fmt::Debug for Empty {
+      fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+      ) -> fmt::Result {
+        write!(f, ".")
+      }
+    }
+
+    let shown = SIZE.min(debug::MAX_VERTICES);
+
+    let mut width = debug::width(&shown.saturating_sub(1));
+    for row in self.data.iter().take(shown) {
+      for weight in row.iter().take(shown).flatten() {
+        width = width.max(debug::width(weight));
+      }
+    }
+
+    debug::write_header(f, shown, SIZE, width)?;
+
+    for i in 0..shown {
+      debug::write_label(f, i, shown)?;
+
+      for j in 0..shown {
+        write!(f, " ")?;
+        match &self.data[i][j] {
+          Some(weight) => {
+            debug::write_padded(f, width, weight)?
+          }
+          None => debug::write_padded(f, width, &Empty)?,
+        }
+      }
+
+      debug::write_row_end(f, i, shown, SIZE)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// An iterator over the neighbors of a vertex and the
+/// weights of the edges to them, returned by
+/// [WeightedGraph::neighbors_weighted].
+#[derive(Clone)]
+pub struct WeightedNeighbors<'a, const SIZE: usize, W: Copy>
+{
+  edges: &'a [Option<W>; SIZE],
+  start: usize,
+  end: usize,
+}
+
+impl<const SIZE: usize, W: Copy> Iterator
+  for WeightedNeighbors<'_, SIZE, W>
+{
+  type Item = (usize, W);
+
+  fn next(&mut self) -> Option<(usize, W)> {
+    while self.start < self.end {
+      let neighbor = self.start;
+      self.start += 1;
+
+      if let Some(weight) = self.edges[neighbor] {
+        return Some((neighbor, weight));
+      }
+    }
+
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(self.end - self.start))
+  }
+}
+
+impl<const SIZE: usize, W: Copy> DoubleEndedIterator
+  for WeightedNeighbors<'_, SIZE, W>
+{
+  fn next_back(&mut self) -> Option<(usize, W)> {
+    while self.start < self.end {
+      self.end -= 1;
+
+      if let Some(weight) = self.edges[self.end] {
+        return Some((self.end, weight));
+      }
+    }
+
+    None
+  }
+}
+
+/// An iterator over the edges of a graph and their weights,
+/// returned by [WeightedGraph::edges].
+#[derive(Clone)]
+pub struct WeightedEdges<'a, const SIZE: usize, W: Copy> {
+  data: &'a [[Option<W>; SIZE]; SIZE],
+  /// The index of the next edge in the flattened matrix.
+  position: usize,
+}
+
+impl<const SIZE: usize, W: Copy> Iterator
+  for WeightedEdges<'_, SIZE, W>
+{
+  type Item = (usize, usize, W);
+
+  fn next(&mut self) -> Option<(usize, usize, W)> {
+    while self.position < SIZE * SIZE {
+      let (i, j) =
+        (self.position / SIZE, self.position % SIZE);
+      self.position += 1;
+
+      if let Some(weight) = self.data[i][j] {
+        return Some((i, j, weight));
+      }
+    }
+
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(SIZE * SIZE - self.position))
+  }
+}
+
+impl<const SIZE: usize, W: Copy + fmt::Display>
+  WeightedGraph<SIZE, W>
+{
+  /// Returns the graph in the Graphviz DOT format, with
+  /// one line per edge labeled with its weight.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(2, 0, 2.0);
+  /// assert_eq!(
+  ///   graph.to_dot(),
+  ///   "digraph {\n\
+  ///   \x20 0 -> 1 [label=\"0.5\"];\n\
+  ///   \x20 2 -> 0 [label=\"2\"];\n\
+  ///   }\n"
+  /// );
+  /// ```
+  /// See also [WeightedGraph::to_dot_undirected].
+  #[cfg(feature = "alloc")]
+  pub fn to_dot(&self) -> String {
+    let mut dot = String::from("digraph {\n");
+
+    for i in 0..SIZE {
+      for (j, weight) in self.neighbors_weighted(i) {
+        // Writing to a String never fails.
+        let _ = writeln!(
+          dot,
+          "  {i} -> {j} [label=\"{weight}\"];"
+        );
+      }
+    }
+
+    dot.push_str("}\n");
+    dot
+  }
+
+  /// Returns the graph in the Graphviz DOT format as an
+  /// undirected graph, with one line per pair of vertices
+  /// with an edge between them in any direction.
+  /// When there are edges in both directions, the label
+  /// is the weight of the one from the smallest vertex.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge_undirected(0, 1, 0.5);
+  /// graph.add_edge(2, 0, 2.0);
+  /// assert_eq!(
+  ///   graph.to_dot_undirected(),
+  ///   "graph {\n\
+  ///   \x20 0 -- 1 [label=\"0.5\"];\n\
+  ///   \x20 0 -- 2 [label=\"2\"];\n\
+  ///   }\n"
+  /// );
+  /// ```
+  /// See also [WeightedGraph::to_dot].
+  #[cfg(feature = "alloc")]
+  pub fn to_dot_undirected(&self) -> String {
+    let mut dot = String::from("graph {\n");
+
+    for i in 0..SIZE {
+      for j in i..SIZE {
+        if let Some(weight) =
+          self.data[i][j].or(self.data[j][i])
+        {
+          // Writing to a String never fails.
+          let _ = writeln!(
+            dot,
+            "  {i} -- {j} [label=\"{weight}\"];"
+          );
+        }
+      }
+    }
+
+    dot.push_str("}\n");
+    dot
+  }
+}
+
+impl<const SIZE: usize, W: Copy + PartialEq>
+  WeightedGraph<SIZE, W>
+{
+  /// Checks whether the graph is symmetric, that is, for
+  /// every edge between `i` and `j` there is also an edge
+  /// between `j` and `i` with the same weight, so the graph
+  /// can be seen as undirected.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge_undirected(0, 1, 0.5);
+  /// assert!(graph.is_symmetric());
+  ///
+  /// graph.add_edge(1, 0, 0.7);
+  /// assert!(!graph.is_symmetric());
+  /// ```
+  pub fn is_symmetric(&self) -> bool {
+    for i in 0..SIZE {
+      for j in i + 1..SIZE {
+        if self.data[i][j] != self.data[j][i] {
+          return false;
+        }
+      }
+    }
+
+    true
+  }
+}
+
+impl<const SIZE: usize> WeightedGraph<SIZE, f32> {
+  /// Returns the sum of the weights of all the edges of the
+  /// graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge_undirected(1, 2, 2.0);
+  /// assert_eq!(graph.total_weight(), 4.5);
+  /// ```
+  pub const fn total_weight(&self) -> f32 {
+    let mut total = 0.0;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          total += weight;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    total
+  }
+
+  /// Returns the largest weight of the edges of the graph,
+  /// or `None` if it has no edges.
+  /// `NaN` weights make the result meaningless.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// assert_eq!(graph.max_weight(), None);
+  ///
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, -2.0);
+  /// assert_eq!(graph.max_weight(), Some(0.5));
+  /// ```
+  /// See also [WeightedGraph::min_weight].
+  pub const fn max_weight(&self) -> Option<f32> {
+    let mut max = None;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          max = match max {
+            Some(max) if max >= weight => Some(max),
+            _ => Some(weight),
+          };
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    max
+  }
+
+  /// Returns the smallest weight of the edges of the graph,
+  /// or `None` if it has no edges.
+  /// `NaN` weights make the result meaningless.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// assert_eq!(graph.min_weight(), None);
+  ///
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, -2.0);
+  /// assert_eq!(graph.min_weight(), Some(-2.0));
+  /// ```
+  /// See also [WeightedGraph::max_weight].
+  pub const fn min_weight(&self) -> Option<f32> {
+    let mut min = None;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          min = match min {
+            Some(min) if min <= weight => Some(min),
+            _ => Some(weight),
+          };
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    min
+  }
+
+  /// Returns a new graph with the same edges as the graph,
+  /// with the inverse of each weight, like to turn
+  /// similarities into distances.
+  /// Following the floating point rules, the edges with a
+  /// weight of 0.0 get a weight of infinity, and the other
+  /// way around.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 0.0);
+  /// let inverted = graph.invert_weights();
+  /// assert_eq!(inverted.get_edge(0, 1), Some(2.0));
+  /// assert_eq!(
+  ///   inverted.get_edge(1, 2),
+  ///   Some(f32::INFINITY)
+  /// );
+  /// assert!(!inverted.has_edge(2, 0));
+  /// ```
+  pub const fn invert_weights(
+    &self,
+  ) -> WeightedGraph<SIZE> {
+    let mut inverted = *self;
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          inverted.data[i][j] = Some(1.0 / weight);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    inverted
+  }
+
+  /// Returns a new graph with the same edges as the graph,
+  /// with each weight divided by the largest one, so the
+  /// non-negative weights are scaled into `[0.0, 1.0]`.
+  /// If the graph has no edges or the largest weight isn't
+  /// positive, it's returned unchanged.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// assert!(graph.normalize_weights() == graph);
+  ///
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 4.0);
+  /// let normalized = graph.normalize_weights();
+  /// assert_eq!(normalized.get_edge(0, 1), Some(0.25));
+  /// assert_eq!(normalized.get_edge(1, 2), Some(1.0));
+  /// ```
+  /// See also [WeightedGraph::max_weight].
+  pub const fn normalize_weights(
+    &self,
+  ) -> WeightedGraph<SIZE> {
+    let mut normalized = *self;
+
+    let max = match self.max_weight() {
+      Some(max) if max > 0.0 => max,
+      _ => return normalized,
+    };
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          normalized.data[i][j] = Some(weight / max);
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    normalized
+  }
+
+  /// Returns a new graph with only the edges whose weight
+  /// is greater than or equal to `cutoff`, so an
+  /// edge whose weight is exactly `cutoff` is kept.
+  /// The edges with a `NaN` weight are always dropped, and
+  /// every other edge is kept by exactly one of
+  /// [WeightedGraph::threshold] and
+  /// [WeightedGraph::threshold_below] with the same
+  /// `cutoff`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 0.8);
+  /// let binarized = graph.threshold(0.8);
+  /// assert!(!binarized.has_edge(0, 1));
+  /// assert!(binarized.has_edge(1, 2));
+  /// assert!(!binarized.has_edge(2, 0));
+  /// ```
+  /// See also [WeightedGraph::threshold_below].
+  pub const fn threshold(
+    &self,
+    cutoff: f32,
+  ) -> Graph<SIZE> {
+    let mut data = [[false; SIZE]; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          data[i][j] = weight >= cutoff;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    Graph::from_matrix(data)
+  }
+
+  /// Returns a new graph with only the edges whose weight
+  /// is strictly smaller than `cutoff`, so an edge
+  /// whose weight is exactly `cutoff` is dropped.
+  /// The edges with a `NaN` weight are always dropped, and
+  /// every other edge is kept by exactly one of
+  /// [WeightedGraph::threshold] and
+  /// [WeightedGraph::threshold_below] with the same
+  /// `cutoff`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 0.8);
+  /// let binarized = graph.threshold_below(0.8);
+  /// assert!(binarized.has_edge(0, 1));
+  /// assert!(!binarized.has_edge(1, 2));
+  /// assert!(!binarized.has_edge(2, 0));
+  /// ```
+  /// See also [WeightedGraph::threshold].
+  pub const fn threshold_below(
+    &self,
+    cutoff: f32,
+  ) -> Graph<SIZE> {
+    let mut data = [[false; SIZE]; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          data[i][j] = weight < cutoff;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    Graph::from_matrix(data)
+  }
+
+  /// Returns the average weight of the edges of the graph,
+  /// or `None` if it has no edges.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// assert_eq!(graph.average_weight(), None);
+  ///
+  /// graph.add_edge(0, 1, 0.5);
+  /// graph.add_edge(1, 2, 2.5);
+  /// assert_eq!(graph.average_weight(), Some(1.5));
+  /// ```
+  /// See also [WeightedGraph::total_weight].
+  pub const fn average_weight(&self) -> Option<f32> {
+    let mut edges = 0;
+
+    let mut i = 0;
+    while i < SIZE {
+      edges += self.out_degree(i);
+
+      i += 1;
+    }
+
+    if edges == 0 {
+      None
+    } else {
+      Some(self.total_weight() / edges as f32)
+    }
+  }
+
+  /// Returns a minimum spanning forest of the graph, only
+  /// spanning the component of vertex 0 unless `forest`,
+  /// and its total weight.
+  const fn prim(
+    &self,
+    forest: bool,
+  ) -> (WeightedGraph<SIZE, f32>, f32) {
+    let mut tree = WeightedGraph::new();
+    let mut total = 0.0;
+
+    let mut in_tree = [false; SIZE];
+    // The lightest edge between each vertex and the tree.
+    let mut lightest = [None; SIZE];
+    let mut parents = [0; SIZE];
+
+    let mut added = 0;
+    while added < SIZE {
+      let mut current = usize::MAX;
+      let mut current_weight = 0.0;
+
+      let mut vertex = 0;
+      while vertex < SIZE {
+        if !in_tree[vertex] {
+          if let Some(weight) = lightest[vertex] {
+            if current == usize::MAX
+              || weight < current_weight
+            {
+              current = vertex;
+              current_weight = weight;
+            }
+          }
+        }
+
+        vertex += 1;
+      }
+
+      if current == usize::MAX {
+        // Nothing else is connected to the tree.
+        if added > 0 && !forest {
+          break;
+        }
+
+        current = 0;
+        while in_tree[current] {
+          current += 1;
+        }
+      } else {
+        tree.add_edge_undirected(
+          parents[current],
+          current,
+          current_weight,
+        );
+        total += current_weight;
+      }
+
+      in_tree[current] = true;
+      added += 1;
+
+      let mut neighbor = 0;
+      while neighbor < SIZE {
+        if !in_tree[neighbor] {
+          if let Some(weight) = self.data[current][neighbor]
+          {
+            let lighter = match lightest[neighbor] {
+              Some(lightest) => weight < lightest,
+              None => true,
+            };
+
+            if lighter {
+              lightest[neighbor] = Some(weight);
+              parents[neighbor] = current;
+            }
+          }
+        }
+
+        neighbor += 1;
+      }
+    }
+
+    (tree, total)
+  }
+
+  /// Returns a minimum spanning tree of the component of
+  /// vertex 0, that is, a new graph with the undirected
+  /// edges of smallest total weight that connect every
+  /// vertex reachable from 0, using Prim's algorithm.
+  /// The other vertices are left isolated.
+  ///
+  /// The graph is assumed to be symmetric, see
+  /// [WeightedGraph::is_symmetric].
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge_undirected(0, 1, 1.0);
+  /// graph.add_edge_undirected(1, 2, 2.0);
+  /// graph.add_edge_undirected(0, 2, 5.0);
+  /// let tree = graph.minimum_spanning_tree();
+  /// assert_eq!(tree.get_edge(0, 1), Some(1.0));
+  /// assert_eq!(tree.get_edge(2, 1), Some(2.0));
+  /// assert!(!tree.has_edge(0, 2));
+  /// ```
+  /// See also [WeightedGraph::minimum_spanning_forest].
+  pub const fn minimum_spanning_tree(
+    &self,
+  ) -> WeightedGraph<SIZE, f32> {
+    self.prim(false).0
+  }
+
+  /// Returns a minimum spanning forest of the graph, that
+  /// is, a minimum spanning tree of each of its connected
+  /// components, using Prim's algorithm.
+  ///
+  /// The graph is assumed to be symmetric, see
+  /// [WeightedGraph::is_symmetric].
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge_undirected(0, 1, 1.0);
+  /// graph.add_edge_undirected(2, 3, 2.0);
+  /// let forest = graph.minimum_spanning_forest();
+  /// assert!(forest.has_edge(0, 1));
+  /// assert!(forest.has_edge(2, 3));
+  /// let tree = graph.minimum_spanning_tree();
+  /// assert!(!tree.has_edge(2, 3));
+  /// ```
+  /// See also [WeightedGraph::minimum_spanning_tree].
+  pub const fn minimum_spanning_forest(
+    &self,
+  ) -> WeightedGraph<SIZE, f32> {
+    self.prim(true).0
+  }
+
+  /// Returns the total weight of the minimum spanning tree
+  /// of the graph.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge_undirected(0, 1, 1.0);
+  /// graph.add_edge_undirected(1, 2, 2.0);
+  /// graph.add_edge_undirected(0, 2, 5.0);
+  /// assert_eq!(graph.mst_total_weight(), 3.0);
+  /// ```
+  /// See also [WeightedGraph::minimum_spanning_tree].
+  pub const fn mst_total_weight(&self) -> f32 {
+    self.prim(false).1
+  }
+
+  /// Gets the weight of the edge between `i` and `j`, or
+  /// infinity if there is none, which is how a missing edge
+  /// is treated when relaxing distances.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<10>::new();
+  /// graph.add_edge(0, 1, 16.0);
+  /// assert_eq!(graph.get_edge_or_infinity(0, 1), 16.0);
+  /// assert_eq!(
+  ///   graph.get_edge_or_infinity(1, 0),
+  ///   f32::INFINITY
+  /// );
+  /// ```
+  /// See also [WeightedGraph::get_edge_or].
+  pub const fn get_edge_or_infinity(
+    &self,
+    i: usize,
+    j: usize,
+  ) -> f32 {
+    self.get_edge_or(i, j, f32::INFINITY)
+  }
+
+  /// Returns the shortest distance between each pair of
+  /// vertices and the matrix used by
+  /// [WeightedGraph::floyd_warshall_paths].
+  #[allow(clippy::type_complexity)]
+  const fn floyd_warshall_matrices(
+    &self,
+  ) -> (
+    [[Option<f32>; SIZE]; SIZE],
+    [[Option<usize>; SIZE]; SIZE],
+  ) {
+    let mut distances = self.data;
+    let mut next = [[None; SIZE]; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if distances[i][j].is_some() {
+          next[i][j] = Some(j);
+        }
+
+        j += 1;
+      }
+
+      // Only negative self-loops are shorter than staying.
+      distances[i][i] = match distances[i][i] {
+        Some(weight) if weight < 0.0 => Some(weight),
+        _ => Some(0.0),
+      };
+      next[i][i] = Some(i);
+
+      i += 1;
+    }
+
+    let mut k = 0;
+    while k < SIZE {
+      let mut i = 0;
+      while i < SIZE {
+        let mut j = 0;
+        while j < SIZE {
+          if let (Some(first), Some(second)) =
+            (distances[i][k], distances[k][j])
+          {
+            let distance = first + second;
+
+            let shorter = match distances[i][j] {
+              Some(current) => distance < current,
+              None => true,
+            };
+
+            if shorter {
+              distances[i][j] = Some(distance);
+              next[i][j] = next[i][k];
+            }
+          }
+
+          j += 1;
+        }
+        i += 1;
+      }
+      k += 1;
+    }
+
+    (distances, next)
+  }
+
+  /// Returns the shortest distance between each pair of
+  /// vertices, with `None` when there is no path between
+  /// them, using the Floyd-Warshall algorithm.
+  /// The distance from a vertex to itself is `0.0` unless
+  /// the vertex is on a negative cycle, including a
+  /// negative self-loop, and then it's negative.
+  ///
+  /// Negative weights are allowed, but if there is a
+  /// negative cycle the distances between the vertices
+  /// that reach it are meaningless.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 2.0);
+  /// graph.add_edge(0, 2, 5.0);
+  /// let distances = graph.floyd_warshall();
+  /// assert_eq!(distances[0][2], Some(3.0));
+  /// assert_eq!(distances[1][1], Some(0.0));
+  /// assert_eq!(distances[2][0], None);
+  ///
+  /// // The self-loop is a negative cycle, so it is taken
+  /// // more than once.
+  /// graph.add_self_loop(1, -1.0);
+  /// let distances = graph.floyd_warshall();
+  /// assert_eq!(distances[1][1], Some(-2.0));
+  /// assert_eq!(distances[0][0], Some(0.0));
+  /// ```
+  /// See also [WeightedGraph::floyd_warshall_paths].
+  pub const fn floyd_warshall(
+    &self,
+  ) -> [[Option<f32>; SIZE]; SIZE] {
+    self.floyd_warshall_matrices().0
+  }
+
+  /// Returns the shortest distance between each pair of
+  /// vertices, with `None` when there is no path between
+  /// them, just like [WeightedGraph::floyd_warshall].
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 2.0);
+  /// assert_eq!(
+  ///   graph.distance_matrix(),
+  ///   [
+  ///     [Some(0.0), Some(1.0), Some(3.0)],
+  ///     [None, Some(0.0), Some(2.0)],
+  ///     [None, None, Some(0.0)],
+  ///   ]
+  /// );
+  /// ```
+  pub const fn distance_matrix(
+    &self,
+  ) -> [[Option<f32>; SIZE]; SIZE] {
+    self.floyd_warshall()
+  }
+
+  /// Returns a matrix where `next[i][j]` is the vertex that
+  /// follows `i` in a shortest path between `i` and `j`, or
+  /// `None` when there is no path between them.
+  /// Following it from `i` until reaching `j` gives the
+  /// path.
+  ///
+  /// Just like [WeightedGraph::floyd_warshall], the paths
+  /// are meaningless when there is a negative cycle.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<3>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 2.0);
+  /// graph.add_edge(0, 2, 5.0);
+  /// let next = graph.floyd_warshall_paths();
+  ///
+  /// let mut path = vec![0];
+  /// let mut current = 0;
+  /// while current != 2 {
+  ///   current = next[current][2].unwrap();
+  ///   path.push(current);
+  /// }
+  /// assert_eq!(path, [0, 1, 2]);
+  /// assert_eq!(next[2][0], None);
+  /// ```
+  pub const fn floyd_warshall_paths(
+    &self,
+  ) -> [[Option<usize>; SIZE]; SIZE] {
+    self.floyd_warshall_matrices().1
+  }
+
+  /// Returns the shortest distance from `start` to each
+  /// vertex, with `None` for the vertices that can't be
+  /// reached from it, using the Bellman-Ford algorithm.
+  /// Unlike [WeightedGraph::dijkstra], negative weights are
+  /// allowed, and `None` is returned if a negative cycle
+  /// can be reached from `start`.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 4.0);
+  /// graph.add_edge(0, 2, 1.0);
+  /// graph.add_edge(1, 2, -5.0);
+  /// assert_eq!(
+  ///   graph.bellman_ford(0),
+  ///   Some([Some(0.0), Some(4.0), Some(-1.0), None])
+  /// );
+  ///
+  /// graph.add_edge(2, 1, 2.0);
+  /// assert_eq!(graph.bellman_ford(0), None);
+  /// assert!(graph.bellman_ford(3).is_some());
+  /// ```
+  pub const fn bellman_ford(
+    &self,
+    start: usize,
+  ) -> Option<[Option<f32>; SIZE]> {
+    let mut distances = [None; SIZE];
+    distances[start] = Some(0.0);
+
+    // After `SIZE - 1` passes, the distances only change
+    // again if there is a negative cycle.
+    let mut pass = 0;
+    while pass < SIZE {
+      let mut changed = false;
+
+      let mut i = 0;
+      while i < SIZE {
+        if let Some(distance) = distances[i] {
+          let mut j = 0;
+          while j < SIZE {
+            if let Some(weight) = self.data[i][j] {
+              let shorter = match distances[j] {
+                Some(current) => {
+                  distance + weight < current
+                }
+                None => true,
+              };
+
+              if shorter {
+                distances[j] = Some(distance + weight);
+                changed = true;
+              }
+            }
+
+            j += 1;
+          }
+        }
+
+        i += 1;
+      }
+
+      if !changed {
+        return Some(distances);
+      }
+
+      pass += 1;
+    }
+
+    None
+  }
+
+  /// Returns the longest distance from `start` to each
+  /// vertex, with `None` for the vertices that can't be
+  /// reached from it, like the critical path of a schedule,
+  /// or `None` if the graph has a cycle.
+  /// The vertices are visited in a topological order, so
+  /// the graph must be acyclic, self-loops included, but
+  /// negative weights are allowed.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<5>::new();
+  /// graph.add_edge(0, 1, 3.0);
+  /// graph.add_edge(0, 2, 2.0);
+  /// graph.add_edge(1, 3, 1.0);
+  /// graph.add_edge(2, 3, 4.0);
+  /// graph.add_edge(4, 0, -1.0);
+  /// let distances = graph.longest_path(0).unwrap();
+  /// assert_eq!(distances[1], Some(3.0));
+  /// assert_eq!(distances[3], Some(6.0));
+  /// assert_eq!(distances[4], None);
+  ///
+  /// graph.add_edge(3, 4, 1.0);
+  /// assert_eq!(graph.longest_path(0), None);
+  /// ```
+  /// See also [WeightedGraph::bellman_ford].
+  pub const fn longest_path(
+    &self,
+    start: usize,
+  ) -> Option<[Option<f32>; SIZE]> {
+    // Kahn's algorithm: the vertices without incoming
+    // edges left are visited next, so if some are never
+    // visited they are in a cycle.
+    let mut in_degrees = [0; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if self.data[i][j].is_some() {
+          in_degrees[j] += 1;
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    let mut queue = [0; SIZE];
+    let mut tail = 0;
+
+    let mut vertex = 0;
+    while vertex < SIZE {
+      if in_degrees[vertex] == 0 {
+        queue[tail] = vertex;
+        tail += 1;
+      }
+
+      vertex += 1;
+    }
+
+    let mut distances = [None; SIZE];
+    distances[start] = Some(0.0);
+
+    let mut head = 0;
+    while head < tail {
+      let current = queue[head];
+      head += 1;
+
+      let mut neighbor = 0;
+      while neighbor < SIZE {
+        if let Some(weight) = self.data[current][neighbor] {
+          if let Some(distance) = distances[current] {
+            let longer = match distances[neighbor] {
+              Some(longest) => distance + weight > longest,
+              None => true,
+            };
+
+            if longer {
+              distances[neighbor] = Some(distance + weight);
+            }
+          }
+
+          in_degrees[neighbor] -= 1;
+          if in_degrees[neighbor] == 0 {
+            queue[tail] = neighbor;
+            tail += 1;
+          }
+        }
+
+        neighbor += 1;
+      }
+    }
+
+    if tail < SIZE {
+      return None;
+    }
+
+    Some(distances)
+  }
+
+  /// Returns the residual capacities after sending as much
+  /// flow as possible from `source` to `sink`, and the
+  /// value of that flow, using the Edmonds-Karp algorithm.
+  const fn max_flow_residual(
+    &self,
+    source: usize,
+    sink: usize,
+  ) -> ([[f32; SIZE]; SIZE], f32) {
+    let mut residual = [[0.0; SIZE]; SIZE];
+
+    let mut i = 0;
+    while i < SIZE {
+      let mut j = 0;
+      while j < SIZE {
+        if let Some(weight) = self.data[i][j] {
+          if i != j && weight > 0.0 {
+            residual[i][j] = weight;
+          }
+        }
+
+        j += 1;
+      }
+      i += 1;
+    }
+
+    let mut flow = 0.0;
+    if source == sink {
+      return (residual, flow);
+    }
+
+    let mut queue = [0; SIZE];
+    loop {
+      // A shortest path with residual capacity left.
+      let mut predecessors = [usize::MAX; SIZE];
+      predecessors[source] = source;
+      queue[0] = source;
+      let mut head = 0;
+      let mut tail = 1;
+
+      while head < tail && predecessors[sink] == usize::MAX
+      {
+        let current = queue[head];
+        head += 1;
+
+        let mut neighbor = 0;
+        while neighbor < SIZE {
+          if predecessors[neighbor] == usize::MAX
+            && residual[current][neighbor] > 0.0
+          {
+            predecessors[neighbor] = current;
+
+            queue[tail] = neighbor;
+            tail += 1;
+          }
+
+          neighbor += 1;
+        }
+      }
+
+      if predecessors[sink] == usize::MAX {
+        return (residual, flow);
+      }
+
+      let mut bottleneck = f32::INFINITY;
+      let mut current = sink;
+      while current != source {
+        let previous = predecessors[current];
+        if residual[previous][current] < bottleneck {
+          bottleneck = residual[previous][current];
+        }
+
+        current = previous;
+      }
+
+      let mut current = sink;
+      while current != source {
+        let previous = predecessors[current];
+        residual[previous][current] -= bottleneck;
+        residual[current][previous] += bottleneck;
+
+        current = previous;
+      }
+
+      flow += bottleneck;
+    }
+  }
+
+  /// Returns the maximum flow from `source` to `sink`,
+  /// using the Edmonds-Karp algorithm.
+  /// The weight of the edge between `i` and `j` is the
+  /// capacity from `i` to `j`, and it is independent of
+  /// the edge between `j` and `i`, so undirected edges
+  /// carry flow in both directions.
+  /// Missing edges, self-loops and edges with a negative
+  /// weight have no capacity, and the flow from a vertex to
+  /// itself is 0.0.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 3.0);
+  /// graph.add_edge(0, 2, 2.0);
+  /// graph.add_edge(1, 2, 1.0);
+  /// graph.add_edge(1, 3, 2.0);
+  /// graph.add_edge(2, 3, 4.0);
+  /// assert_eq!(graph.max_flow(0, 3), 5.0);
+  /// assert_eq!(graph.max_flow(3, 0), 0.0);
+  /// ```
+  /// See also [WeightedGraph::min_cut].
+  pub const fn max_flow(
+    &self,
+    source: usize,
+    sink: usize,
+  ) -> f32 {
+    self.max_flow_residual(source, sink).1
+  }
+}
+
+/// A vertex in the priority queues of
+/// [WeightedGraph::dijkstra] and [WeightedGraph::astar],
+/// ordered so the closest one is the greatest.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy)]
+struct Visit {
+  distance: f32,
+  vertex: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Visit {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for Visit {}
+
+#[cfg(feature = "alloc")]
+impl PartialOrd for Visit {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl Ord for Visit {
+  fn cmp(&self, other: &Self) -> Ordering {
+    other
+      .distance
+      .total_cmp(&self.distance)
+      .then_with(|| other.vertex.cmp(&self.vertex))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<const SIZE: usize> WeightedGraph<SIZE, f32> {
+  /// Returns the shortest distance from `start` to each
+  /// vertex and the vertex before each one in its shortest
+  /// path, or `usize::MAX` for `start` and the unreachable
+  /// vertices.
+  fn dijkstra_predecessors(
+    &self,
+    start: usize,
+  ) -> ([Option<f32>; SIZE], [usize; SIZE]) {
+    let mut distances = [None; SIZE];
+    let mut predecessors = [usize::MAX; SIZE];
+    let mut visited = [false; SIZE];
+
+    let mut queue = BinaryHeap::new();
+
+    distances[start] = Some(0.0);
+    queue.push(Visit {
+      distance: 0.0,
+      vertex: start,
+    });
+
+    while let Some(Visit { distance, vertex }) = queue.pop()
+    {
+      if visited[vertex] {
+        continue;
+      }
+      visited[vertex] = true;
+
+      for (neighbor, weight) in
+        self.neighbors_weighted(vertex)
+      {
+        debug_assert!(
+          weight >= 0.0,
+          "Dijkstra's algorithm doesn't support negative \
+           weights"
+        );
+
+        let candidate = distance + weight;
+        if distances[neighbor]
+          .is_none_or(|current| candidate < current)
+        {
+          distances[neighbor] = Some(candidate);
+          predecessors[neighbor] = vertex;
+          queue.push(Visit {
+            distance: candidate,
+            vertex: neighbor,
+          });
+        }
+      }
+    }
+
+    (distances, predecessors)
+  }
+
+  /// Returns the shortest distance from `start` to each
+  /// vertex, with `None` for the unreachable ones, using
+  /// Dijkstra's algorithm.
+  /// Requires the `alloc` feature.
+  ///
+  /// Negative weights are not supported: they panic in
+  /// debug builds, and give wrong distances otherwise; use
+  /// [WeightedGraph::floyd_warshall] for them.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 2.0);
+  /// graph.add_edge(0, 2, 5.0);
+  /// assert_eq!(
+  ///   graph.dijkstra(0),
+  ///   [Some(0.0), Some(1.0), Some(3.0), None]
+  /// );
+  /// ```
+  /// See also [WeightedGraph::dijkstra_path].
+  pub fn dijkstra(
+    &self,
+    start: usize,
+  ) -> [Option<f32>; SIZE] {
+    self.dijkstra_predecessors(start).0
+  }
+
+  /// Returns a shortest path between `start` and `end`, or
+  /// `None` if there is no path between them, using
+  /// Dijkstra's algorithm.
+  /// Requires the `alloc` feature.
+  ///
+  /// Just like [WeightedGraph::dijkstra], negative weights
+  /// are not supported.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 2.0);
+  /// graph.add_edge(0, 2, 5.0);
+  /// assert_eq!(
+  ///   graph.dijkstra_path(0, 2),
+  ///   Some(vec![0, 1, 2])
+  /// );
+  /// assert_eq!(graph.dijkstra_path(0, 3), None);
+  /// ```
+  pub fn dijkstra_path(
+    &self,
+    start: usize,
+    end: usize,
+  ) -> Option<Vec<usize>> {
+    let (distances, predecessors) =
+      self.dijkstra_predecessors(start);
+
+    distances[end]?;
+
+    Some(Self::path_to(&predecessors, end))
+  }
+
+  /// Returns up to `k` shortest paths between `start` and
+  /// `end` without repeated vertices, with their total
+  /// weights, in increasing order of weight, using Yen's
+  /// algorithm.
+  /// The paths with the same weight are in lexicographic
+  /// order, and there are fewer than `k` of them if there
+  /// aren't that many paths.
+  /// Requires the `alloc` feature.
+  ///
+  /// Just like [WeightedGraph::dijkstra], negative weights
+  /// are not supported.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<5>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 3, 1.0);
+  /// graph.add_edge(0, 2, 1.0);
+  /// graph.add_edge(2, 3, 2.0);
+  /// graph.add_edge(1, 2, 1.0);
+  /// assert_eq!(
+  ///   graph.k_shortest_paths(0, 3, 5),
+  ///   [
+  ///     (2.0, vec![0, 1, 3]),
+  ///     (3.0, vec![0, 2, 3]),
+  ///     (4.0, vec![0, 1, 2, 3]),
+  ///   ]
+  /// );
+  /// assert_eq!(
+  ///   graph.k_shortest_paths(0, 3, 1),
+  ///   [(2.0, vec![0, 1, 3])]
+  /// );
+  /// assert!(graph.k_shortest_paths(0, 4, 5).is_empty());
+  /// ```
+  /// See also [WeightedGraph::dijkstra_path].
+  pub fn k_shortest_paths(
+    &self,
+    start: usize,
+    end: usize,
+    k: usize,
+  ) -> Vec<(f32, Vec<usize>)> {
+    let mut paths: Vec<(f32, Vec<usize>)> = Vec::new();
+    let mut candidates: Vec<(f32, Vec<usize>)> = Vec::new();
+
+    if k == 0 {
+      return paths;
+    }
+
+    let (distances, predecessors) =
+      self.dijkstra_predecessors(start);
+    match distances[end] {
+      Some(distance) => paths.push((
+        distance,
+        Self::path_to(&predecessors, end),
+      )),
+      None => return paths,
+    }
+
+    while paths.len() < k {
+      let last = &paths[paths.len() - 1].1;
+
+      // Each candidate follows the last path up to a spur
+      // vertex, and then deviates from every path found
+      // with the same root.
+      let mut root_weight = 0.0;
+      for spur in 0..last.len() - 1 {
+        let root = &last[..=spur];
+
+        let mut graph = *self;
+        for (_, path) in &paths {
+          if path.len() > spur + 1 && path[..=spur] == *root
+          {
+            graph.remove_edge(path[spur], path[spur + 1]);
+          }
+        }
+        for &vertex in &root[..spur] {
+          graph.remove_vertex(vertex);
+        }
+
+        let (distances, predecessors) =
+          graph.dijkstra_predecessors(last[spur]);
+        if let Some(distance) = distances[end] {
+          let mut path = Vec::from(&root[..spur]);
+          path.extend(Self::path_to(&predecessors, end));
+
+          let candidate = (root_weight + distance, path);
+          if !candidates.contains(&candidate)
+            && !paths.iter().any(|(_, p)| *p == candidate.1)
+          {
+            candidates.push(candidate);
+          }
+        }
+
+        if let Some(weight) =
+          self.data[last[spur]][last[spur + 1]]
+        {
+          root_weight += weight;
+        }
+      }
+
+      let best = candidates
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+          a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1))
+        })
+        .map(|(index, _)| index);
+
+      match best {
+        Some(index) => {
+          paths.push(candidates.swap_remove(index))
+        }
+        None => break,
+      }
+    }
+
+    paths
+  }
+
+  /// Returns the path that ends at `end` following
+  /// `predecessors`, which is `usize::MAX` for its start.
+  fn path_to(
+    predecessors: &[usize; SIZE],
+    end: usize,
+  ) -> Vec<usize> {
+    let mut path = Vec::from([end]);
+    let mut current = end;
+    while predecessors[current] != usize::MAX {
+      current = predecessors[current];
+      path.push(current);
+    }
+
+    path.reverse();
+
+    path
+  }
+
+  /// Returns a shortest path between `start` and `goal`, or
+  /// `None` if there is no path between them, using the A*
+  /// algorithm, which visits first the vertices for which
+  /// the distance from `start` plus `heuristic` is the
+  /// smallest.
+  /// Requires the `alloc` feature.
+  ///
+  /// The path is only guaranteed to be a shortest one if
+  /// the weights are non-negative and the heuristic is
+  /// admissible, that is, `heuristic(v)` is never greater
+  /// than the distance between `v` and `goal`.
+  /// Negative weights panic in debug builds.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// // The vertices are in a line, at distance 1 apart.
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 1.0);
+  /// graph.add_edge(1, 2, 1.0);
+  /// graph.add_edge(0, 2, 3.0);
+  /// let heuristic = |vertex: usize| (2 - vertex) as f32;
+  /// assert_eq!(
+  ///   graph.astar(0, 2, heuristic),
+  ///   Some(vec![0, 1, 2])
+  /// );
+  /// assert_eq!(graph.astar(0, 3, |_| 0.0), None);
+  /// ```
+  /// See also [WeightedGraph::dijkstra_path].
+  pub fn astar<H: Fn(usize) -> f32>(
+    &self,
+    start: usize,
+    goal: usize,
+    heuristic: H,
+  ) -> Option<Vec<usize>> {
+    let mut distances: [Option<f32>; SIZE] = [None; SIZE];
+    let mut predecessors = [usize::MAX; SIZE];
+
+    let mut queue = BinaryHeap::new();
+
+    distances[start] = Some(0.0);
+    queue.push(Visit {
+      distance: heuristic(start),
+      vertex: start,
+    });
+
+    while let Some(Visit { distance, vertex }) = queue.pop()
+    {
+      if vertex == goal {
+        return Some(Self::path_to(&predecessors, goal));
+      }
+
+      let current = match distances[vertex] {
+        Some(current) => current,
+        None => continue,
+      };
+      // A vertex is pushed again each time a shorter path
+      // to it is found, so the old visits are skipped.
+      if distance > current + heuristic(vertex) {
+        continue;
+      }
+
+      for (neighbor, weight) in
+        self.neighbors_weighted(vertex)
+      {
+        debug_assert!(
+          weight >= 0.0,
+          "the A* algorithm doesn't support negative \
+           weights"
+        );
+
+        let candidate = current + weight;
+        if distances[neighbor]
+          .is_none_or(|distance| candidate < distance)
+        {
+          distances[neighbor] = Some(candidate);
+          predecessors[neighbor] = vertex;
+          queue.push(Visit {
+            distance: candidate + heuristic(neighbor),
+            vertex: neighbor,
+          });
+        }
+      }
+    }
+
+    None
+  }
+
+  /// Returns the edges of a minimum cut between `source`
+  /// and `sink`, that is, edges with the smallest total
+  /// capacity whose removal leaves no path between them,
+  /// in row order.
+  /// The capacities are the same as in
+  /// [WeightedGraph::max_flow], whose value is their total
+  /// weight, and the cut is the one closest to `source`.
+  /// Requires the `alloc` feature.
+  /// ```
+  /// use const_graphs::WeightedGraph;
+  ///
+  /// let mut graph = WeightedGraph::<4>::new();
+  /// graph.add_edge(0, 1, 3.0);
+  /// graph.add_edge(0, 2, 2.0);
+  /// graph.add_edge(1, 2, 1.0);
+  /// graph.add_edge(1, 3, 2.0);
+  /// graph.add_edge(2, 3, 4.0);
+  /// assert_eq!(graph.min_cut(0, 3), vec![(0, 1), (0, 2)]);
+  /// assert_eq!(graph.min_cut(3, 0), vec![]);
+  /// ```
+  pub fn min_cut(
+    &self,
+    source: usize,
+    sink: usize,
+  ) -> Vec<(usize, usize)> {
+    let (residual, _) =
+      self.max_flow_residual(source, sink);
+
+    // The vertices that can still be reached from the
+    // source are on its side of the cut.
+    let mut reachable = [false; SIZE];
+    reachable[source] = true;
+    let mut stack = Vec::from([source]);
+    while let Some(current) = stack.pop() {
+      for neighbor in 0..SIZE {
+        if !reachable[neighbor]
+          && residual[current][neighbor] > 0.0
+        {
+          reachable[neighbor] = true;
+          stack.push(neighbor);
+        }
+      }
+    }
+
+    self
+      .edges()
+      .filter(|&(i, j, weight)| {
+        reachable[i] && !reachable[j] && weight > 0.0
+      })
+      .map(|(i, j, _)| (i, j))
+      .collect()
+  }
 }